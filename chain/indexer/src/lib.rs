@@ -16,6 +16,8 @@ pub use unc_indexer_primitives::{
 
 mod streamer;
 
+pub use streamer::{convert_transactions_sir_into_local_receipts_batch, FailedToFetchData};
+
 pub const INDEXER: &str = "indexer";
 
 /// Config wrapper to simplify signature and usage of `framework::init_configs`
@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use rocksdb::DB;
+use unc_primitives::types::{BlockHeight, ShardId};
+
+const LAST_SYNCED_BLOCK_HEIGHT_KEY: &[u8] = b"last_synced_block_height";
+const LAST_SYNCED_SHARD_HEIGHTS_KEY: &[u8] = b"last_synced_shard_heights";
+
+/// Keeps track of the Streamer's progress in the indexer database so that
+/// syncing can be resumed after a restart.
+///
+/// Apart from the height of the last processed block, the last processed
+/// height is recorded for every shard separately since shards don't
+/// necessarily advance together (a block may lack a chunk for some shard).
+pub(crate) struct CheckpointStore {
+    db: DB,
+    shard_heights: HashMap<ShardId, BlockHeight>,
+}
+
+impl CheckpointStore {
+    /// Opens (or creates) the indexer database at `path` and restores the
+    /// per-shard heights persisted there.
+    pub(crate) fn open(path: &Path) -> anyhow::Result<Self> {
+        let db = DB::open_default(path)?;
+        let shard_heights = match db.get(LAST_SYNCED_SHARD_HEIGHTS_KEY)? {
+            Some(value) => serde_json::from_slice(&value)?,
+            None => HashMap::new(),
+        };
+        Ok(Self { db, shard_heights })
+    }
+
+    /// Returns the height of the last block processed by the Streamer.
+    pub(crate) fn last_synced_block_height(&self) -> anyhow::Result<Option<BlockHeight>> {
+        match self.db.get(LAST_SYNCED_BLOCK_HEIGHT_KEY)? {
+            Some(value) => Ok(Some(String::from_utf8(value)?.parse()?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the last processed height of every shard seen so far.
+    pub(crate) fn shard_heights(&self) -> &HashMap<ShardId, BlockHeight> {
+        &self.shard_heights
+    }
+
+    /// Returns the height the given shard should be resumed from, i.e. the
+    /// height following the last one processed for it, or `None` if the shard
+    /// hasn't been processed yet.
+    pub(crate) fn resume_height(&self, shard_id: ShardId) -> Option<BlockHeight> {
+        self.shard_heights.get(&shard_id).map(|height| height + 1)
+    }
+
+    /// Returns the height syncing should be resumed from: the lowest
+    /// [`Self::resume_height`] among the known shards, or the last processed
+    /// block if no shard has been recorded yet.
+    pub(crate) fn resume_from(&self) -> anyhow::Result<Option<BlockHeight>> {
+        match self.shard_heights.keys().filter_map(|shard_id| self.resume_height(*shard_id)).min() {
+            Some(height) => Ok(Some(height)),
+            None => self.last_synced_block_height(),
+        }
+    }
+
+    /// Records that `shard_id` has been processed up to `height`.  The change
+    /// is persisted on the next [`Self::save`] call.
+    pub(crate) fn set_shard_height(&mut self, shard_id: ShardId, height: BlockHeight) {
+        self.shard_heights.insert(shard_id, height);
+    }
+
+    /// Persists `block_height` as the last processed block together with the
+    /// current per-shard heights.
+    pub(crate) fn save(&self, block_height: BlockHeight) -> anyhow::Result<()> {
+        let mut batch = rocksdb::WriteBatch::default();
+        batch.put(LAST_SYNCED_BLOCK_HEIGHT_KEY, block_height.to_string());
+        batch.put(LAST_SYNCED_SHARD_HEIGHTS_KEY, serde_json::to_vec(&self.shard_heights)?);
+        self.db.write(batch)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shard_heights_are_restored() {
+        let dir = tempfile::tempdir().unwrap();
+        {
+            let mut checkpoint = CheckpointStore::open(dir.path()).unwrap();
+            assert_eq!(checkpoint.last_synced_block_height().unwrap(), None);
+            assert_eq!(checkpoint.resume_height(0), None);
+            assert_eq!(checkpoint.resume_from().unwrap(), None);
+            checkpoint.set_shard_height(0, 100);
+            checkpoint.set_shard_height(1, 98);
+            checkpoint.save(100).unwrap();
+        }
+
+        let checkpoint = CheckpointStore::open(dir.path()).unwrap();
+        assert_eq!(checkpoint.last_synced_block_height().unwrap(), Some(100));
+        assert_eq!(checkpoint.shard_heights(), &HashMap::from([(0, 100), (1, 98)]));
+        assert_eq!(checkpoint.resume_height(0), Some(101));
+        assert_eq!(checkpoint.resume_height(1), Some(99));
+        assert_eq!(checkpoint.resume_height(2), None);
+        assert_eq!(checkpoint.resume_from().unwrap(), Some(99));
+    }
+}
@@ -1,14 +1,26 @@
 use actix::MailboxError;
+use unc_primitives::hash::CryptoHash;
 
 /// Error occurs in case of failed data fetch
-#[derive(Debug)]
+#[derive(Debug, thiserror::Error)]
 pub enum FailedToFetchData {
-    MailboxError(MailboxError),
+    #[error("view client mailbox error: {0}")]
+    MailboxError(#[from] MailboxError),
+    #[error("{0}")]
     String(String),
+    /// Fetched data couldn't be converted, e.g. it contains an action the
+    /// indexer doesn't support yet.
+    #[error("failed to convert fetched data: {0}")]
+    ConversionError(String),
+    /// Computing the cost of the transaction with the given hash overflowed.
+    #[error("cost of transaction {0} overflows")]
+    TransactionCostOverflow(CryptoHash),
 }
 
-impl From<MailboxError> for FailedToFetchData {
-    fn from(actix_error: MailboxError) -> Self {
-        FailedToFetchData::MailboxError(actix_error)
+impl FailedToFetchData {
+    /// Returns whether the fetch may succeed if retried, i.e. the view client
+    /// didn't respond in time.
+    pub(crate) fn is_transient(&self) -> bool {
+        matches!(self, FailedToFetchData::MailboxError(MailboxError::Timeout))
     }
 }
@@ -1,6 +1,7 @@
 //! Streamer watches the network and collects all the blocks and related chunks
 //! into one struct and pushes in in to the given queue
 use std::collections::HashMap;
+use std::time::Duration;
 
 use actix::Addr;
 use futures::stream::StreamExt;
@@ -67,6 +68,57 @@ pub(crate) async fn fetch_block(
         .map_err(|err| FailedToFetchData::String(err.to_string()))
 }
 
+/// Exponential backoff between retries of a failed fetch.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Backoff {
+    /// Delay before the first retry.
+    pub initial: Duration,
+    /// Upper bound on the delay, it stops doubling once reached.
+    pub max: Duration,
+}
+
+/// Runs `fetch` and retries it up to `retries` times while it fails with a
+/// transient error (see [`FailedToFetchData::is_transient`]), sleeping between
+/// attempts with exponentially growing delay.  The last error is returned
+/// once the retries are exhausted.
+pub(crate) async fn retry_with_backoff<T, F, Fut>(
+    retries: u32,
+    backoff: Backoff,
+    mut fetch: F,
+) -> Result<T, FailedToFetchData>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<T, FailedToFetchData>>,
+{
+    let mut delay = backoff.initial;
+    let mut attempt = 0;
+    loop {
+        match fetch().await {
+            Err(err) if err.is_transient() && attempt < retries => {
+                attempt += 1;
+                warn!(
+                    target: INDEXER,
+                    "Fetch failed with {:?}, retrying in {:?} ({}/{})", err, delay, attempt, retries
+                );
+                tokio::time::sleep(delay).await;
+                delay = std::cmp::min(delay.saturating_mul(2), backoff.max);
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Same as [`fetch_block`] but retries transient failures, see
+/// [`retry_with_backoff`].
+pub(crate) async fn fetch_block_with_retry(
+    client: &Addr<unc_client::ViewClientActor>,
+    hash: CryptoHash,
+    retries: u32,
+    backoff: Backoff,
+) -> Result<views::BlockView, FailedToFetchData> {
+    retry_with_backoff(retries, backoff, || fetch_block(client, hash)).await
+}
+
 pub(crate) async fn fetch_state_changes(
     client: &Addr<unc_client::ViewClientActor>,
     block_hash: CryptoHash,
@@ -180,3 +232,46 @@ pub(crate) async fn fetch_protocol_config(
         .await?
         .map_err(|err| FailedToFetchData::String(err.to_string()))?)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix::MailboxError;
+
+    const BACKOFF: Backoff =
+        Backoff { initial: Duration::from_millis(1), max: Duration::from_millis(2) };
+
+    #[tokio::test]
+    async fn test_retry_recovers_from_transient_errors() {
+        let mut calls = 0;
+        let result = retry_with_backoff(3, BACKOFF, || {
+            calls += 1;
+            let result = if calls <= 2 { Err(MailboxError::Timeout.into()) } else { Ok(calls) };
+            async move { result }
+        })
+        .await;
+        assert_eq!(result.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_gives_up() {
+        let mut calls = 0;
+        let result: Result<(), _> = retry_with_backoff(2, BACKOFF, || {
+            calls += 1;
+            async { Err(MailboxError::Timeout.into()) }
+        })
+        .await;
+        assert!(matches!(result, Err(FailedToFetchData::MailboxError(MailboxError::Timeout))));
+        assert_eq!(calls, 3);
+
+        // Non-transient errors aren't retried.
+        let mut calls = 0;
+        let result: Result<(), _> = retry_with_backoff(2, BACKOFF, || {
+            calls += 1;
+            async { Err(FailedToFetchData::String("unknown block".to_string())) }
+        })
+        .await;
+        assert!(matches!(result, Err(FailedToFetchData::String(_))));
+        assert_eq!(calls, 1);
+    }
+}
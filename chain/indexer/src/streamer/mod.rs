@@ -1,15 +1,18 @@
-use self::errors::FailedToFetchData;
+use self::checkpoint::CheckpointStore;
 use self::fetchers::{
     fetch_block, fetch_block_by_height, fetch_block_chunks, fetch_latest_block, fetch_outcomes,
     fetch_state_changes, fetch_status,
 };
-use self::utils::convert_transactions_sir_into_local_receipts;
+use self::utils::{
+    convert_transactions_sir_into_local_receipts,
+    convert_transactions_sir_into_local_receipts_with_gas_price, fetch_prev_block_gas_price,
+};
 use crate::streamer::fetchers::fetch_protocol_config;
 use crate::INDEXER;
 use crate::{AwaitForNodeSyncedEnum, IndexerConfig};
 use actix::Addr;
 use async_recursion::async_recursion;
-use rocksdb::DB;
+use std::collections::HashMap;
 use std::time::Duration;
 use tokio::sync::mpsc;
 use tokio::time;
@@ -21,8 +24,13 @@ use unc_indexer_primitives::{
 };
 use unc_parameters::RuntimeConfig;
 use unc_primitives::hash::CryptoHash;
+use unc_primitives::types::{BlockHeight, ShardId};
 use unc_primitives::views;
 
+pub use self::errors::FailedToFetchData;
+pub use self::utils::convert_transactions_sir_into_local_receipts_batch;
+
+mod checkpoint;
 mod errors;
 mod fetchers;
 mod metrics;
@@ -94,6 +102,7 @@ async fn build_streamer_message(
         })
         .collect::<Vec<_>>();
 
+    let mut prev_block_gas_price = None;
     for chunk in chunks {
         let views::ChunkView { transactions, author, header, receipts: chunk_non_local_receipts } =
             chunk;
@@ -119,16 +128,28 @@ async fn build_streamer_message(
             })
             .collect::<Vec<IndexerTransactionWithOutcome>>();
 
-        let chunk_local_receipts = convert_transactions_sir_into_local_receipts(
-            &client,
-            &runtime_config,
-            indexer_transactions
-                .iter()
-                .filter(|tx| tx.transaction.signer_id == tx.transaction.receiver_id)
-                .collect::<Vec<&IndexerTransactionWithOutcome>>(),
-            &block,
-        )
-        .await?;
+        let local_transactions = indexer_transactions
+            .iter()
+            .filter(|tx| tx.transaction.signer_id == tx.transaction.receiver_id)
+            .collect::<Vec<&IndexerTransactionWithOutcome>>();
+        let chunk_local_receipts = if local_transactions.is_empty() {
+            vec![]
+        } else {
+            // The gas price is the same for all chunks of the block so it's
+            // fetched at most once.
+            let gas_price = match prev_block_gas_price {
+                Some(gas_price) => gas_price,
+                None => {
+                    *prev_block_gas_price.insert(fetch_prev_block_gas_price(&client, &block).await?)
+                }
+            };
+            convert_transactions_sir_into_local_receipts_with_gas_price(
+                &runtime_config,
+                local_transactions,
+                &block,
+                gas_price,
+            )?
+        };
 
         // Add local receipts to corresponding outcomes
         for receipt in &chunk_local_receipts {
@@ -254,13 +275,7 @@ async fn find_local_receipt_by_id_in_block(
 
         if let Some((transaction, outcome)) =
             transactions.into_iter().zip(outcomes.into_iter()).find(|(_, outcome)| {
-                outcome
-                    .execution_outcome
-                    .outcome
-                    .receipt_ids
-                    .first()
-                    .expect("The transaction ExecutionOutcome should have one receipt id in vec")
-                    == &receipt_id
+                outcome.execution_outcome.outcome.receipt_ids.first() == Some(&receipt_id)
             })
         {
             let indexer_transaction = IndexerTransactionWithOutcome { transaction, outcome };
@@ -297,8 +312,25 @@ pub(crate) async fn start(
             .join("indexer");
 
     // TODO: implement proper error handling
-    let db = DB::open_default(indexer_db_path).unwrap();
-    let mut last_synced_block_height: Option<unc_primitives::types::BlockHeight> = None;
+    let mut checkpoint = CheckpointStore::open(&indexer_db_path).unwrap();
+    // Shards already processed past the block syncing is resumed from are
+    // skipped until they catch up with their own checkpoint.
+    let resume_heights: HashMap<ShardId, BlockHeight> =
+        if let crate::SyncModeEnum::FromInterruption = indexer_config.sync_mode {
+            checkpoint
+                .shard_heights()
+                .keys()
+                .filter_map(|shard_id| {
+                    checkpoint.resume_height(*shard_id).map(|height| (*shard_id, height))
+                })
+                .collect()
+        } else {
+            HashMap::new()
+        };
+    for (shard_id, height) in &resume_heights {
+        debug!(target: INDEXER, "Shard #{} is to be resumed from block #{}", shard_id, height);
+    }
+    let mut last_synced_block_height: Option<BlockHeight> = None;
 
     'main: loop {
         time::sleep(INTERVAL).await;
@@ -327,12 +359,10 @@ pub(crate) async fn start(
             last_synced_block_height + 1
         } else {
             match indexer_config.sync_mode {
-                crate::SyncModeEnum::FromInterruption => {
-                    match db.get(b"last_synced_block_height").unwrap() {
-                        Some(value) => String::from_utf8(value).unwrap().parse::<u64>().unwrap(),
-                        None => latest_block_height,
-                    }
-                }
+                crate::SyncModeEnum::FromInterruption => match checkpoint.resume_from().unwrap() {
+                    Some(value) => value,
+                    None => latest_block_height,
+                },
                 crate::SyncModeEnum::LatestSynced => latest_block_height,
                 crate::SyncModeEnum::BlockHeight(height) => height,
             }
@@ -352,8 +382,19 @@ pub(crate) async fn start(
                 let response = build_streamer_message(&view_client, block).await;
 
                 match response {
-                    Ok(streamer_message) => {
+                    Ok(mut streamer_message) => {
+                        streamer_message.shards.retain(|shard| {
+                            resume_heights
+                                .get(&shard.shard_id)
+                                .map_or(true, |resume_height| block_height >= *resume_height)
+                        });
                         debug!(target: INDEXER, "{:#?}", &streamer_message);
+                        let processed_shards: Vec<ShardId> = streamer_message
+                            .shards
+                            .iter()
+                            .filter(|shard| shard.chunk.is_some())
+                            .map(|shard| shard.shard_id)
+                            .collect();
                         if blocks_sink.send(streamer_message).await.is_err() {
                             info!(
                                 target: INDEXER,
@@ -362,6 +403,9 @@ pub(crate) async fn start(
                             break 'main;
                         } else {
                             metrics::NUM_STREAMER_MESSAGES_SENT.inc();
+                            for shard_id in processed_shards {
+                                checkpoint.set_shard_height(shard_id, block_height);
+                            }
                         }
                     }
                     Err(err) => {
@@ -373,7 +417,7 @@ pub(crate) async fn start(
                     }
                 }
             }
-            db.put(b"last_synced_block_height", &block_height.to_string()).unwrap();
+            checkpoint.save(block_height).unwrap();
             last_synced_block_height = Some(block_height);
         }
     }
@@ -1,13 +1,32 @@
 use actix::Addr;
+use std::collections::HashMap;
+use std::time::Duration;
+use tracing::warn;
 
 use node_runtime::config::tx_cost;
 use unc_indexer_primitives::IndexerTransactionWithOutcome;
 use unc_parameters::RuntimeConfig;
+use unc_primitives::hash::CryptoHash;
+use unc_primitives::types::Balance;
 use unc_primitives::views;
 
 use super::errors::FailedToFetchData;
-use super::fetchers::fetch_block;
+use super::fetchers::{fetch_block_with_retry, Backoff};
+use crate::INDEXER;
 
+/// How many times fetching the previous block is retried on transient errors.
+const FETCH_PREV_BLOCK_RETRIES: u32 = 5;
+const FETCH_PREV_BLOCK_BACKOFF: Backoff =
+    Backoff { initial: Duration::from_millis(100), max: Duration::from_secs(2) };
+
+/// Converts transactions signed by the receiver into the local receipts they
+/// produce.  Those receipts are never shared between shards so the node
+/// doesn't store them and the indexer has to reconstruct them.
+///
+/// Fetches the previous block to get the gas price the receipts were bought
+/// with.  Callers converting several batches of transactions of the same block
+/// should fetch the price once and use
+/// [`convert_transactions_sir_into_local_receipts_with_gas_price`] instead.
 pub(crate) async fn convert_transactions_sir_into_local_receipts(
     client: &Addr<unc_client::ViewClientActor>,
     runtime_config: &RuntimeConfig,
@@ -17,52 +36,329 @@ pub(crate) async fn convert_transactions_sir_into_local_receipts(
     if txs.is_empty() {
         return Ok(vec![]);
     }
-    let prev_block = fetch_block(&client, block.header.prev_hash).await?;
-    let prev_block_gas_price = prev_block.header.gas_price;
-
-    let local_receipts: Vec<views::ReceiptView> =
-        txs.into_iter()
-            .map(|tx| {
-                let cost = tx_cost(
-                    &runtime_config,
-                    &unc_primitives::transaction::Transaction {
-                        signer_id: tx.transaction.signer_id.clone(),
-                        public_key: tx.transaction.public_key.clone(),
-                        nonce: tx.transaction.nonce,
-                        receiver_id: tx.transaction.receiver_id.clone(),
-                        block_hash: block.header.hash,
-                        actions: tx
-                            .transaction
-                            .actions
-                            .clone()
-                            .into_iter()
-                            .map(|action| {
-                                unc_primitives::transaction::Action::try_from(action).unwrap()
-                            })
-                            .collect(),
-                    },
-                    prev_block_gas_price,
-                    true,
-                );
-                views::ReceiptView {
-                    predecessor_id: tx.transaction.signer_id.clone(),
-                    receiver_id: tx.transaction.receiver_id.clone(),
-                    receipt_id: *tx.outcome.execution_outcome.outcome.receipt_ids.first().expect(
-                        "The transaction ExecutionOutcome should have one receipt id in vec",
-                    ),
-                    receipt: views::ReceiptEnumView::Action {
-                        signer_id: tx.transaction.signer_id.clone(),
-                        signer_public_key: tx.transaction.public_key.clone(),
-                        gas_price: cost
-                            .expect("TransactionCost returned IntegerOverflowError")
-                            .receipt_gas_price,
-                        output_data_receivers: vec![],
-                        input_data_ids: vec![],
-                        actions: tx.transaction.actions.clone(),
-                    },
-                }
+    let prev_block_gas_price = fetch_prev_block_gas_price(client, block).await?;
+    convert_transactions_sir_into_local_receipts_with_gas_price(
+        runtime_config,
+        txs,
+        block,
+        prev_block_gas_price,
+    )
+}
+
+/// Fetches the gas price of the block preceding `block`, which is the price
+/// the local receipts of `block` are bought with.
+pub(crate) async fn fetch_prev_block_gas_price(
+    client: &Addr<unc_client::ViewClientActor>,
+    block: &views::BlockView,
+) -> Result<Balance, FailedToFetchData> {
+    let prev_block = fetch_block_with_retry(
+        client,
+        block.header.prev_hash,
+        FETCH_PREV_BLOCK_RETRIES,
+        FETCH_PREV_BLOCK_BACKOFF,
+    )
+    .await?;
+    Ok(prev_block.header.gas_price)
+}
+
+/// Batched version of [`convert_transactions_sir_into_local_receipts`] for a
+/// window of blocks, each given together with its transactions signed by the
+/// receiver.
+///
+/// The result is aligned with the input: its `i`-th element holds the local
+/// receipts of the `i`-th block, in the order of that block's transactions
+/// (transactions without a receipt id are skipped).
+///
+/// The previous block of every block is fetched at most once.  If it's part of
+/// the batch itself (e.g. the batch is a range of consecutive blocks) its gas
+/// price is taken from there and nothing is fetched, regardless of where in
+/// the batch it's placed.  Blocks without transactions don't fetch anything.
+pub async fn convert_transactions_sir_into_local_receipts_batch(
+    client: &Addr<unc_client::ViewClientActor>,
+    runtime_config: &RuntimeConfig,
+    blocks: Vec<(&views::BlockView, Vec<&IndexerTransactionWithOutcome>)>,
+) -> Result<Vec<Vec<views::ReceiptView>>, FailedToFetchData> {
+    convert_transactions_sir_into_local_receipts_batch_with(runtime_config, blocks, |block| {
+        fetch_prev_block_gas_price(client, block)
+    })
+    .await
+}
+
+/// Same as [`convert_transactions_sir_into_local_receipts_batch`] but gets the
+/// gas price of previous blocks which aren't part of the batch from
+/// `fetch_prev_block_gas_price`.
+async fn convert_transactions_sir_into_local_receipts_batch_with<'a, F, Fut>(
+    runtime_config: &RuntimeConfig,
+    blocks: Vec<(&'a views::BlockView, Vec<&IndexerTransactionWithOutcome>)>,
+    mut fetch_prev_block_gas_price: F,
+) -> Result<Vec<Vec<views::ReceiptView>>, FailedToFetchData>
+where
+    F: FnMut(&'a views::BlockView) -> Fut,
+    Fut: std::future::Future<Output = Result<Balance, FailedToFetchData>>,
+{
+    let mut gas_prices: HashMap<CryptoHash, Balance> =
+        blocks.iter().map(|(block, _)| (block.header.hash, block.header.gas_price)).collect();
+    let mut local_receipts = Vec::with_capacity(blocks.len());
+    for (block, txs) in blocks {
+        if txs.is_empty() {
+            local_receipts.push(vec![]);
+            continue;
+        }
+        let prev_block_gas_price = match gas_prices.get(&block.header.prev_hash) {
+            Some(gas_price) => *gas_price,
+            None => {
+                let gas_price = fetch_prev_block_gas_price(block).await?;
+                gas_prices.insert(block.header.prev_hash, gas_price);
+                gas_price
+            }
+        };
+        local_receipts.push(convert_transactions_sir_into_local_receipts_with_gas_price(
+            runtime_config,
+            txs,
+            block,
+            prev_block_gas_price,
+        )?);
+    }
+    Ok(local_receipts)
+}
+
+/// Same as [`convert_transactions_sir_into_local_receipts`] but takes the gas
+/// price of the block preceding `block` rather than fetching it.
+///
+/// `prev_block_gas_price` must be the gas price of the block with hash
+/// `block.header.prev_hash`, e.g. as returned by [`fetch_prev_block_gas_price`].
+/// It may be reused for any number of calls with the same `block`, but not
+/// across blocks.
+pub(crate) fn convert_transactions_sir_into_local_receipts_with_gas_price(
+    runtime_config: &RuntimeConfig,
+    txs: Vec<&IndexerTransactionWithOutcome>,
+    block: &views::BlockView,
+    prev_block_gas_price: Balance,
+) -> Result<Vec<views::ReceiptView>, FailedToFetchData> {
+    let mut local_receipts = Vec::with_capacity(txs.len());
+    for tx in txs {
+        local_receipts.extend(convert_transaction_into_local_receipt(
+            runtime_config,
+            tx,
+            block.header.hash,
+            prev_block_gas_price,
+        )?);
+    }
+    Ok(local_receipts)
+}
+
+/// Converts a single transaction into its local receipt.
+///
+/// Returns `None` (and logs a warning) if the transaction's outcome has no
+/// receipt id, which can happen for outcomes streamed during a reorg.  Such a
+/// transaction is skipped rather than bringing the whole indexer down.
+fn convert_transaction_into_local_receipt(
+    runtime_config: &RuntimeConfig,
+    tx: &IndexerTransactionWithOutcome,
+    block_hash: CryptoHash,
+    prev_block_gas_price: Balance,
+) -> Result<Option<views::ReceiptView>, FailedToFetchData> {
+    let Some(receipt_id) = tx.outcome.execution_outcome.outcome.receipt_ids.first() else {
+        warn!(
+            target: INDEXER,
+            "Transaction {} has no receipt id in its ExecutionOutcome, skipping its local receipt",
+            tx.transaction.hash
+        );
+        return Ok(None);
+    };
+    let cost = tx_cost(
+        &runtime_config,
+        &unc_primitives::transaction::Transaction {
+            signer_id: tx.transaction.signer_id.clone(),
+            public_key: tx.transaction.public_key.clone(),
+            nonce: tx.transaction.nonce,
+            receiver_id: tx.transaction.receiver_id.clone(),
+            block_hash,
+            actions: convert_actions(tx.transaction.actions.clone())?,
+        },
+        prev_block_gas_price,
+        true,
+    )
+    .map_err(|_| FailedToFetchData::TransactionCostOverflow(tx.transaction.hash))?;
+    Ok(Some(views::ReceiptView {
+        predecessor_id: tx.transaction.signer_id.clone(),
+        receiver_id: tx.transaction.receiver_id.clone(),
+        receipt_id: *receipt_id,
+        receipt: views::ReceiptEnumView::Action {
+            signer_id: tx.transaction.signer_id.clone(),
+            signer_public_key: tx.transaction.public_key.clone(),
+            gas_price: cost.receipt_gas_price,
+            output_data_receivers: vec![],
+            input_data_ids: vec![],
+            actions: tx.transaction.actions.clone(),
+        },
+    }))
+}
+
+/// Converts action views into actions failing on the first one which can't be
+/// converted, e.g. an action introduced by a protocol upgrade the indexer
+/// doesn't know about yet.
+fn convert_actions<A>(
+    actions: impl IntoIterator<Item = A>,
+) -> Result<Vec<unc_primitives::transaction::Action>, FailedToFetchData>
+where
+    A: TryInto<unc_primitives::transaction::Action>,
+    A::Error: std::fmt::Display,
+{
+    actions
+        .into_iter()
+        .map(|action| {
+            action.try_into().map_err(|err| {
+                FailedToFetchData::ConversionError(format!("failed to convert action: {err}"))
             })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use unc_indexer_primitives::IndexerExecutionOutcomeWithOptionalReceipt;
+    use unc_primitives::transaction::{ExecutionOutcomeWithIdAndProof, SignedTransaction};
+
+    fn transaction_with_receipt_ids(receipt_ids: Vec<CryptoHash>) -> IndexerTransactionWithOutcome {
+        let transaction = SignedTransaction::empty(CryptoHash::default());
+        let mut outcome = ExecutionOutcomeWithIdAndProof::default();
+        outcome.outcome_with_id.id = transaction.get_hash();
+        outcome.outcome_with_id.outcome.receipt_ids = receipt_ids;
+        IndexerTransactionWithOutcome {
+            transaction: transaction.into(),
+            outcome: IndexerExecutionOutcomeWithOptionalReceipt {
+                execution_outcome: outcome.into(),
+                receipt: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_outcome_without_receipt_id_is_skipped() {
+        let runtime_config = RuntimeConfig::test();
+        let receipt_id = CryptoHash::hash_bytes(b"receipt");
+
+        let tx = transaction_with_receipt_ids(vec![]);
+        assert!(convert_transaction_into_local_receipt(
+            &runtime_config,
+            &tx,
+            CryptoHash::default(),
+            1
+        )
+        .unwrap()
+        .is_none());
+
+        let tx = transaction_with_receipt_ids(vec![receipt_id]);
+        let receipt =
+            convert_transaction_into_local_receipt(&runtime_config, &tx, CryptoHash::default(), 1)
+                .unwrap()
+                .unwrap();
+        assert_eq!(receipt.receipt_id, receipt_id);
+    }
+
+    fn block_view(hash: CryptoHash, prev_hash: CryptoHash, gas_price: Balance) -> views::BlockView {
+        let genesis = unc_primitives::block::Block::genesis(
+            unc_primitives::version::PROTOCOL_VERSION,
+            vec![],
+            unc_primitives::static_clock::StaticClock::utc(),
+            0,
+            gas_price,
+            0,
+            CryptoHash::default(),
+        );
+        let mut block = views::BlockView::from_author_block("test".parse().unwrap(), genesis);
+        block.header.hash = hash;
+        block.header.prev_hash = prev_hash;
+        block
+    }
+
+    #[tokio::test]
+    async fn test_batch_preserves_order() {
+        let runtime_config = RuntimeConfig::test();
+        let hash = |name: &str| CryptoHash::hash_bytes(name.as_bytes());
+        let block1 = block_view(hash("block1"), hash("block0"), 10);
+        let block2 = block_view(hash("block2"), hash("block1"), 20);
+        let block3 = block_view(hash("block3"), hash("block2"), 30);
+        let txs: Vec<_> = ["tx1", "tx2", "tx3"]
+            .into_iter()
+            .map(|name| transaction_with_receipt_ids(vec![hash(name)]))
             .collect();
 
-    Ok(local_receipts)
+        // Blocks aren't given in chain order and one of them has no
+        // transactions; the output is still aligned with the input.
+        let mut fetched = vec![];
+        let local_receipts = convert_transactions_sir_into_local_receipts_batch_with(
+            &runtime_config,
+            vec![(&block3, vec![&txs[2], &txs[1]]), (&block1, vec![&txs[0]]), (&block2, vec![])],
+            |block| {
+                fetched.push(block.header.prev_hash);
+                async { Ok(1) }
+            },
+        )
+        .await
+        .unwrap();
+
+        let receipt_ids: Vec<Vec<CryptoHash>> = local_receipts
+            .iter()
+            .map(|receipts| receipts.iter().map(|receipt| receipt.receipt_id).collect())
+            .collect();
+        assert_eq!(receipt_ids, vec![vec![hash("tx3"), hash("tx2")], vec![hash("tx1")], vec![]]);
+        // Only the block preceding the batch is fetched.
+        assert_eq!(fetched, vec![hash("block0")]);
+    }
+
+    #[test]
+    fn test_transaction_cost_overflow() {
+        let function_call = unc_primitives::transaction::Action::FunctionCall(Box::new(
+            unc_primitives::transaction::FunctionCallAction {
+                method_name: "main".to_string(),
+                args: vec![],
+                gas: unc_primitives::types::Gas::MAX,
+                deposit: 0,
+            },
+        ));
+        let transaction = SignedTransaction::from_actions(
+            0,
+            "test".parse().unwrap(),
+            "test".parse().unwrap(),
+            &unc_crypto::EmptySigner {},
+            vec![function_call.clone(), function_call],
+            CryptoHash::default(),
+        );
+        let mut tx = transaction_with_receipt_ids(vec![CryptoHash::hash_bytes(b"receipt")]);
+        tx.transaction = transaction.into();
+
+        let result = convert_transaction_into_local_receipt(
+            &RuntimeConfig::test(),
+            &tx,
+            CryptoHash::default(),
+            1,
+        );
+        assert!(matches!(
+            result,
+            Err(FailedToFetchData::TransactionCostOverflow(hash)) if hash == tx.transaction.hash
+        ));
+    }
+
+    struct UnknownAction;
+
+    impl TryFrom<UnknownAction> for unc_primitives::transaction::Action {
+        type Error = String;
+
+        fn try_from(_: UnknownAction) -> Result<Self, Self::Error> {
+            Err("unknown action".to_string())
+        }
+    }
+
+    #[test]
+    fn test_unconvertible_action() {
+        let tx = transaction_with_receipt_ids(vec![]);
+        assert_eq!(convert_actions(tx.transaction.actions).unwrap().len(), 0);
+        assert!(matches!(
+            convert_actions([UnknownAction]),
+            Err(FailedToFetchData::ConversionError(message)) if message.contains("unknown action")
+        ));
+    }
 }
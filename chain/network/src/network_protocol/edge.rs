@@ -2,7 +2,7 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use once_cell::sync::Lazy;
 use std::sync::Arc;
 use unc_async::time;
-use unc_crypto::{KeyType, SecretKey, Signature};
+use unc_crypto::{KeyType, Signature, Signer};
 use unc_primitives::hash::CryptoHash;
 use unc_primitives::network::PeerId;
 
@@ -22,9 +22,9 @@ pub struct PartialEdgeInfo {
 }
 
 impl PartialEdgeInfo {
-    pub fn new(peer0: &PeerId, peer1: &PeerId, nonce: u64, secret_key: &SecretKey) -> Self {
+    pub fn new(peer0: &PeerId, peer1: &PeerId, nonce: u64, signer: &dyn Signer) -> Self {
         let data = Edge::build_hash(peer0, peer1, nonce);
-        let signature = secret_key.sign(data.as_ref());
+        let signature = signer.sign(data.as_ref());
         Self { nonce, signature }
     }
 }
@@ -91,7 +91,7 @@ impl Edge {
         peer0: PeerId,
         peer1: PeerId,
         nonce: u64,
-        secret_key: &SecretKey,
+        signer: &dyn Signer,
         signature1: Signature,
     ) -> Self {
         let hash = if peer0 < peer1 {
@@ -99,7 +99,7 @@ impl Edge {
         } else {
             Self::build_hash(&peer1, &peer0, nonce)
         };
-        let signature0 = secret_key.sign(hash.as_ref());
+        let signature0 = signer.sign(hash.as_ref());
         Self::new(peer0, peer1, nonce, signature0, signature1)
     }
 
@@ -146,13 +146,13 @@ impl Edge {
     }
 
     /// Create the remove edge change from an added edge change.
-    pub fn remove_edge(&self, my_peer_id: PeerId, sk: &SecretKey) -> Edge {
+    pub fn remove_edge(&self, my_peer_id: PeerId, signer: &dyn Signer) -> Edge {
         assert_eq!(self.edge_type(), EdgeState::Active);
         let mut edge = self.0.as_ref().clone();
         edge.nonce += 1;
         let me = edge.key.0 == my_peer_id;
         let hash = edge.hash();
-        let signature = sk.sign(hash.as_ref());
+        let signature = signer.sign(hash.as_ref());
         edge.removal_info = Some((me, signature));
         Edge(Arc::new(edge))
     }
@@ -1,7 +1,7 @@
 use super::MAX_SHARDS_PER_SNAPSHOT_HOST_INFO;
 use crate::network_protocol::Arc;
-use unc_crypto::SecretKey;
 use unc_crypto::Signature;
+use unc_crypto::Signer;
 use unc_primitives::hash::CryptoHash;
 use unc_primitives::network::PeerId;
 use unc_primitives::types::EpochHeight;
@@ -42,12 +42,12 @@ impl SnapshotHostInfo {
         sync_hash: CryptoHash,
         epoch_height: EpochHeight,
         shards: Vec<ShardId>,
-        secret_key: &SecretKey,
+        signer: &dyn Signer,
     ) -> Self {
         #[cfg(not(test))]
-        assert_eq!(&secret_key.public_key(), peer_id.public_key());
+        assert_eq!(&signer.public_key(), peer_id.public_key());
         let hash = Self::build_hash(&sync_hash, &epoch_height, &shards);
-        let signature = secret_key.sign(hash.as_ref());
+        let signature = signer.sign(hash.as_ref());
         Self { peer_id, sync_hash, epoch_height, shards, signature }
     }
 
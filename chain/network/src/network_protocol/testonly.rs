@@ -139,7 +139,7 @@ pub fn make_edge(a: &SecretKey, b: &SecretKey, nonce: u64) -> Edge {
 }
 
 pub fn make_edge_tombstone(a: &SecretKey, b: &SecretKey) -> Edge {
-    make_edge(a, b, 1).remove_edge(PeerId::new(a.public_key()), &a)
+    make_edge(a, b, 1).remove_edge(PeerId::new(a.public_key()), a)
 }
 
 pub fn make_routing_table<R: Rng>(rng: &mut R) -> RoutingTableUpdate {
@@ -13,7 +13,7 @@ use std::io;
 use std::net::SocketAddr;
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use unc_async::time::{Duration, Instant, Utc};
-use unc_crypto::{KeyType, SecretKey};
+use unc_crypto::{KeyType, SecretKey, Signer};
 use unc_primitives::block::{Block, BlockHeader, GenesisId};
 use unc_primitives::hash::CryptoHash;
 use unc_primitives::network::{AnnounceAccount, PeerId};
@@ -186,7 +186,7 @@ impl From<RecvError> for ConnectError {
 }
 
 fn new_handshake(
-    secret_key: &SecretKey,
+    signer: &dyn Signer,
     my_peer_id: &PeerId,
     target_peer_id: &PeerId,
     listen_port: u16,
@@ -212,7 +212,7 @@ fn new_handshake(
             tracked_shards,
             archival,
         },
-        partial_edge_info: PartialEdgeInfo::new(my_peer_id, target_peer_id, nonce, secret_key),
+        partial_edge_info: PartialEdgeInfo::new(my_peer_id, target_peer_id, nonce, signer),
         owned_account: None,
     })
 }
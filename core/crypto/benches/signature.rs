@@ -0,0 +1,81 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::str::FromStr;
+use unc_crypto::{Digest32, KeyType, PublicKey, SecretKey, SharedPublicKey, Signature};
+
+const KEY_TYPES: [KeyType; 3] = [KeyType::ED25519, KeyType::SECP256K1, KeyType::RSA2048];
+
+fn sign(c: &mut Criterion) {
+    let digest = Digest32::from_sha256(b"benchmark");
+    let mut group = c.benchmark_group("sign");
+    for key_type in KEY_TYPES {
+        let secret_key = SecretKey::from_seed(key_type, "bench");
+        group.bench_function(key_type.to_string(), |b| {
+            b.iter(|| secret_key.sign(black_box(digest.as_bytes())))
+        });
+    }
+    group.finish();
+}
+
+fn verify(c: &mut Criterion) {
+    let digest = Digest32::from_sha256(b"benchmark");
+    let mut group = c.benchmark_group("verify");
+    for key_type in KEY_TYPES {
+        let secret_key = SecretKey::from_seed(key_type, "bench");
+        let public_key = secret_key.public_key();
+        let signature = secret_key.sign(digest.as_bytes());
+        group.bench_function(key_type.to_string(), |b| {
+            b.iter(|| assert!(signature.verify(black_box(digest.as_bytes()), &public_key)))
+        });
+    }
+    group.finish();
+}
+
+/// Base58 encoding and decoding goes through the `Display` and `FromStr`
+/// implementations of keys and signatures.
+fn bs58(c: &mut Criterion) {
+    let secret_key = SecretKey::from_seed(KeyType::ED25519, "bench");
+    let public_key = secret_key.public_key();
+    let signature = secret_key.sign(b"benchmark");
+    let encoded_public_key = public_key.to_string();
+    let encoded_signature = signature.to_string();
+
+    let mut group = c.benchmark_group("bs58");
+    group.bench_function("encode_public_key", |b| b.iter(|| black_box(&public_key).to_string()));
+    group.bench_function("decode_public_key", |b| {
+        b.iter(|| PublicKey::from_str(black_box(&encoded_public_key)).unwrap())
+    });
+    group.bench_function("encode_signature", |b| b.iter(|| black_box(&signature).to_string()));
+    group.bench_function("decode_signature", |b| {
+        b.iter(|| Signature::from_str(black_box(&encoded_signature)).unwrap())
+    });
+    group.finish();
+}
+
+/// Cloning a [`SharedPublicKey`] costs the same regardless of the key type
+/// while cloning a `PublicKey` copies the key data.
+fn clone(c: &mut Criterion) {
+    let mut group = c.benchmark_group("clone");
+    for key_type in KEY_TYPES {
+        let public_key = PublicKey::from_seed(key_type, "bench");
+        let shared = SharedPublicKey::from(public_key.clone());
+        group.bench_function(format!("public_key/{key_type}"), |b| {
+            b.iter(|| black_box(&public_key).clone())
+        });
+        group.bench_function(format!("shared_public_key/{key_type}"), |b| {
+            b.iter(|| black_box(&shared).clone())
+        });
+    }
+    group.finish();
+}
+
+/// Cost of setting up the full secp256k1 context used for signing compared to
+/// the verification-only one used with the `secp256k1_verify_only` feature.
+fn secp256k1_context(c: &mut Criterion) {
+    let mut group = c.benchmark_group("secp256k1_context");
+    group.bench_function("all", |b| b.iter(secp256k1::Secp256k1::new));
+    group.bench_function("verification_only", |b| b.iter(secp256k1::Secp256k1::verification_only));
+    group.finish();
+}
+
+criterion_group!(benches, sign, verify, bs58, clone, secp256k1_context);
+criterion_main!(benches);
@@ -0,0 +1,53 @@
+//! Lengths of the raw key and signature data of every supported key type,
+//! i.e. of the bytes following the key type tag in the borsh encoding.
+//!
+//! Useful for sizing buffers and validating input without hardcoding the
+//! numbers.  See also [`KeyType::public_key_len`](crate::KeyType::public_key_len)
+//! and [`KeyType::signature_len`](crate::KeyType::signature_len).
+
+use crate::signature;
+
+pub const ED25519_PUBLIC_KEY_LEN: usize = ed25519_dalek::PUBLIC_KEY_LENGTH;
+/// Secret key together with the public key, as stored in
+/// [`ED25519SecretKey`](crate::ED25519SecretKey).
+pub const ED25519_SECRET_KEY_LEN: usize = ed25519_dalek::KEYPAIR_LENGTH;
+pub const ED25519_SIGNATURE_LEN: usize = ed25519_dalek::SIGNATURE_LENGTH;
+
+/// Uncompressed public key without the leading `0x04` byte.
+pub const SECP256K1_PUBLIC_KEY_LEN: usize = signature::PUBLIC_KEY_SECP256K1_LENGTH;
+/// Signature followed by the recovery id.
+pub const SECP256K1_SIGNATURE_LEN: usize = signature::SECP256K1_SIGNATURE_LENGTH;
+
+/// DER-encoded `SubjectPublicKeyInfo`.
+pub const RSA2048_PUBLIC_KEY_LEN: usize = signature::RAW_PUBLIC_KEY_RSA_2048_LENGTH;
+pub const RSA2048_SIGNATURE_LEN: usize = signature::RSA2048_SIGNATURE_LENGTH;
+
+/// Uncompressed public key without the leading `0x04` byte.
+pub const P256_PUBLIC_KEY_LEN: usize = signature::PUBLIC_KEY_P256_LENGTH;
+pub const P256_SIGNATURE_LEN: usize = signature::P256_SIGNATURE_LENGTH;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{KeyType, PublicKey, SecretKey};
+
+    #[test]
+    fn test_lengths_match_keys() {
+        for (key_type, public_key_len, signature_len) in [
+            (KeyType::ED25519, ED25519_PUBLIC_KEY_LEN, ED25519_SIGNATURE_LEN),
+            (KeyType::SECP256K1, SECP256K1_PUBLIC_KEY_LEN, SECP256K1_SIGNATURE_LEN),
+            (KeyType::RSA2048, RSA2048_PUBLIC_KEY_LEN, RSA2048_SIGNATURE_LEN),
+            (KeyType::P256, P256_PUBLIC_KEY_LEN, P256_SIGNATURE_LEN),
+        ] {
+            assert_eq!(key_type.public_key_len(), public_key_len, "{key_type}");
+            assert_eq!(key_type.signature_len(), Some(signature_len), "{key_type}");
+            let secret_key = SecretKey::from_seed(key_type, "test");
+            assert_eq!(secret_key.public_key().key_data().len(), public_key_len, "{key_type}");
+            assert_eq!(PublicKey::empty(key_type).key_data().len(), public_key_len, "{key_type}");
+        }
+        let SecretKey::ED25519(secret_key) = SecretKey::from_seed(KeyType::ED25519, "test") else {
+            unreachable!()
+        };
+        assert_eq!(secret_key.0.len(), ED25519_SECRET_KEY_LEN);
+    }
+}
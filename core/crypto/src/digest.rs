@@ -0,0 +1,94 @@
+use crate::errors::DigestError;
+
+const DIGEST32_LENGTH: usize = 32;
+
+/// 32-byte message digest, e.g. SHA-256 hash of a message.
+///
+/// secp256k1 signatures are made over such a digest rather than over the
+/// message itself.  Taking `Digest32` rather than a byte slice moves the
+/// length check to where the digest is constructed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Digest32(pub [u8; DIGEST32_LENGTH]);
+
+impl Digest32 {
+    /// Computes SHA-256 digest of `data`.
+    pub fn from_sha256(data: &[u8]) -> Self {
+        use sha2::Digest;
+        Self(sha2::Sha256::digest(data).into())
+    }
+
+    pub fn as_bytes(&self) -> &[u8; DIGEST32_LENGTH] {
+        &self.0
+    }
+}
+
+impl From<[u8; DIGEST32_LENGTH]> for Digest32 {
+    fn from(digest: [u8; DIGEST32_LENGTH]) -> Self {
+        Self(digest)
+    }
+}
+
+impl From<&[u8; DIGEST32_LENGTH]> for Digest32 {
+    fn from(digest: &[u8; DIGEST32_LENGTH]) -> Self {
+        Self(*digest)
+    }
+}
+
+impl From<&Digest32> for Digest32 {
+    fn from(digest: &Digest32) -> Self {
+        *digest
+    }
+}
+
+impl TryFrom<&[u8]> for Digest32 {
+    type Error = DigestError;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        data.try_into().map(Self).map_err(|_| DigestError::InvalidLength {
+            expected_length: DIGEST32_LENGTH,
+            received_length: data.len(),
+        })
+    }
+}
+
+impl AsRef<[u8]> for Digest32 {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl secp256k1::ThirtyTwoByteHash for Digest32 {
+    fn into_32(self) -> [u8; 32] {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{KeyType, SecretKey};
+
+    #[test]
+    fn test_digest32() {
+        let digest = Digest32::from_sha256(b"123");
+        assert_eq!(Digest32::try_from(&digest.0[..]).unwrap(), digest);
+        assert!(matches!(
+            Digest32::try_from(&b"123"[..]),
+            Err(DigestError::InvalidLength { expected_length: 32, received_length: 3 })
+        ));
+
+        let sk = SecretKey::from_seed(KeyType::SECP256K1, "test");
+        let signature = sk.sign_digest(&digest);
+        assert_eq!(signature, sk.sign(&digest.0));
+        assert!(signature.verify_digest(&digest, &sk.public_key()));
+        assert!(signature.verify_digest(digest.as_bytes(), &sk.public_key()));
+        assert_eq!(signature.recover_public_key(digest).unwrap(), sk.public_key());
+        assert_eq!(signature.recover_public_key(digest.0).unwrap(), sk.public_key());
+
+        for key_type in [KeyType::ED25519, KeyType::RSA2048, KeyType::P256] {
+            let sk = SecretKey::from_seed(key_type, "test");
+            let signature = sk.sign_digest(&digest);
+            assert!(signature.verify_digest(&digest, &sk.public_key()), "{key_type}");
+        }
+    }
+}
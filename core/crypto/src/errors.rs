@@ -1,3 +1,4 @@
+use crate::KeyType;
 use unc_account_id::AccountId;
 
 #[derive(Debug, Clone, thiserror::Error)]
@@ -6,6 +7,12 @@ pub enum ParseKeyTypeError {
     UnknownKeyType { unknown_key_type: String },
 }
 
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ParseRsaSchemeError {
+    #[error("unknown RSA signature scheme '{unknown_scheme}'")]
+    UnknownScheme { unknown_scheme: String },
+}
+
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum ParseKeyError {
     #[error("unknown key type '{unknown_key_type}'")]
@@ -14,6 +21,12 @@ pub enum ParseKeyError {
     InvalidLength { expected_length: usize, received_length: usize },
     #[error("invalid key data: {error_message}")]
     InvalidData { error_message: String },
+    #[error("missing key type prefix")]
+    MissingKeyTypePrefix,
+    #[error("unknown key URI scheme '{unknown_scheme}'")]
+    UnknownUriScheme { unknown_scheme: String },
+    #[error("base58check checksum mismatch")]
+    ChecksumMismatch,
 }
 
 impl From<ParseKeyTypeError> for ParseKeyError {
@@ -34,6 +47,10 @@ pub enum ParseSignatureError {
     InvalidLength { expected_length: usize, received_length: usize },
     #[error("invalid signature data: {error_message}")]
     InvalidData { error_message: String },
+    #[error("missing key type prefix")]
+    MissingKeyTypePrefix,
+    #[error("base58check checksum mismatch")]
+    ChecksumMismatch,
 }
 
 impl From<ParseKeyTypeError> for ParseSignatureError {
@@ -46,6 +63,94 @@ impl From<ParseKeyTypeError> for ParseSignatureError {
     }
 }
 
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum KeyConversionError {
+    #[error("expected {expected_key_type} key, but {received_key_type} key was given")]
+    KeyTypeMismatch { expected_key_type: KeyType, received_key_type: KeyType },
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum VerifyError {
+    #[error("invalid message length: expected a digest of {expected_length} bytes, but {received_length} was given")]
+    InvalidMessageLength { expected_length: usize, received_length: usize },
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum VerifyFailure {
+    #[error("signature of type {signature_key_type} can't be verified with {public_key_type} public key")]
+    KeyTypeMismatch { signature_key_type: KeyType, public_key_type: KeyType },
+    #[error("malformed signature")]
+    MalformedSignature,
+    #[error("invalid public key")]
+    InvalidPublicKey,
+    #[error("invalid message")]
+    InvalidMessage,
+    #[error("signature doesn't match the message and public key")]
+    SignatureMismatch,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum DigestError {
+    #[error(
+        "invalid digest length: expected {expected_length} bytes, but {received_length} was given"
+    )]
+    InvalidLength { expected_length: usize, received_length: usize },
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum SigningError {
+    #[error(
+        "invalid message length: expected a digest of {expected} bytes, but {received} was given"
+    )]
+    MessageLength { expected: usize, received: usize },
+    #[error("expected a {expected_key_type} key, but {received_key_type} key was given")]
+    KeyTypeMismatch { expected_key_type: KeyType, received_key_type: KeyType },
+    #[error("invalid salt length: expected at most {max} bytes, but {received} was given")]
+    SaltLength { max: usize, received: usize },
+    #[error("context is too long: expected at most {max} bytes, but {received} was given")]
+    ContextTooLong { max: usize, received: usize },
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum SignerError {
+    #[error(transparent)]
+    Signing(#[from] SigningError),
+    #[error("signer unavailable: {error_message}")]
+    Unavailable { error_message: String },
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ValidationError {
+    #[error("invalid secp256k1 recovery id {recovery_id}")]
+    InvalidRecoveryId { recovery_id: u8 },
+    #[error("signature values are out of range")]
+    InvalidSignatureValues,
+    #[error("ED25519 signature has high bits of its last byte set")]
+    Ed25519HighBits,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum RecoverError {
+    #[error("public key can't be recovered from {key_type} signature")]
+    NotRecoverable { key_type: KeyType },
+    #[error("invalid signature: {error_message}")]
+    InvalidSignature { error_message: String },
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum WifError {
+    #[error("only secp256k1 keys can be encoded in WIF, but {key_type} key was given")]
+    UnsupportedKeyType { key_type: KeyType },
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum DeriveError {
+    #[error("invalid derivation path '{path}'")]
+    InvalidPath { path: String },
+    #[error("non-hardened index {index} can't be used for ed25519 key derivation")]
+    NonHardenedIndex { index: u32 },
+}
+
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum ImplicitPublicKeyError {
     #[error("'{account_id}' is not a UNC-implicit account")]
@@ -0,0 +1,151 @@
+//! Hierarchical deterministic key derivation as specified by
+//! [SLIP-0010](https://github.com/satoshilabs/slips/blob/master/slip-0010.md).
+//!
+//! Only the ed25519 curve is supported.  SLIP-0010 defines only hardened
+//! derivation for ed25519 so every index in the path must be hardened.
+
+use crate::errors::DeriveError;
+use crate::signature::{ED25519SecretKey, SecretKey};
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
+
+const ED25519_CURVE_SEED: &[u8] = b"ed25519 seed";
+const HARDENED_OFFSET: u32 = 1 << 31;
+
+/// Extended private key: the secret key together with its chain code.
+struct ExtendedKey {
+    key: [u8; 32],
+    chain_code: [u8; 32],
+}
+
+impl ExtendedKey {
+    fn from_hmac(key: &[u8], data: &[&[u8]]) -> Self {
+        let mut mac = Hmac::<Sha512>::new_from_slice(key).expect("HMAC can take key of any size");
+        for chunk in data {
+            mac.update(chunk);
+        }
+        let result = mac.finalize().into_bytes();
+        let mut extended_key = Self { key: [0; 32], chain_code: [0; 32] };
+        extended_key.key.copy_from_slice(&result[..32]);
+        extended_key.chain_code.copy_from_slice(&result[32..]);
+        extended_key
+    }
+
+    fn master(seed: &[u8]) -> Self {
+        Self::from_hmac(ED25519_CURVE_SEED, &[seed])
+    }
+
+    fn derive_hardened_child(&self, index: u32) -> Self {
+        let index = index | HARDENED_OFFSET;
+        Self::from_hmac(&self.chain_code, &[&[0u8], &self.key, &index.to_be_bytes()])
+    }
+}
+
+/// Parses path like `m/44'/397'/0'` into a list of (not yet hardened) indices.
+///
+/// `'`, `h` and `H` suffixes are accepted as the hardened marker.
+fn parse_path(path: &str) -> Result<Vec<u32>, DeriveError> {
+    let invalid_path = || DeriveError::InvalidPath { path: path.to_string() };
+    let mut components = path.split('/');
+    if components.next() != Some("m") {
+        return Err(invalid_path());
+    }
+    components
+        .map(|component| {
+            let (index, hardened) = match component.strip_suffix(['\'', 'h', 'H']) {
+                Some(index) => (index, true),
+                None => (component, false),
+            };
+            let index = index.parse::<u32>().map_err(|_| invalid_path())?;
+            if index >= HARDENED_OFFSET {
+                return Err(invalid_path());
+            }
+            if !hardened {
+                return Err(DeriveError::NonHardenedIndex { index });
+            }
+            Ok(index)
+        })
+        .collect()
+}
+
+/// Derives an ed25519 secret key from the master `seed` following the given
+/// BIP32-style `path`, e.g. `m/44'/397'/0'/0'/0'`.
+///
+/// Returns an error if the path is malformed or contains non-hardened indices.
+pub fn derive_ed25519_path(seed: &[u8], path: &str) -> Result<SecretKey, DeriveError> {
+    let key = parse_path(path)?
+        .into_iter()
+        .fold(ExtendedKey::master(seed), |key, index| key.derive_hardened_child(index));
+    let keypair = ed25519_dalek::SigningKey::from_bytes(&key.key);
+    Ok(SecretKey::ED25519(ED25519SecretKey(keypair.to_keypair_bytes())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PublicKey;
+    use hex_literal::hex;
+
+    fn check(seed: &[u8], path: &str, secret: [u8; 32], public: [u8; 32]) {
+        let sk = derive_ed25519_path(seed, path).unwrap();
+        assert_eq!(&sk.unwrap_as_ed25519().0[..32], &secret, "{path}");
+        assert_eq!(sk.public_key(), PublicKey::ED25519(crate::ED25519PublicKey(public)), "{path}");
+    }
+
+    /// Test vector 1 for ed25519 from SLIP-0010.
+    #[test]
+    fn test_slip10_vector_1() {
+        let seed = hex!("000102030405060708090a0b0c0d0e0f");
+        check(
+            &seed,
+            "m",
+            hex!("2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7"),
+            hex!("a4b2856bfec510abab89753fac1ac0e1112364e7d250545963f135f2a33188ed"),
+        );
+        check(
+            &seed,
+            "m/0'",
+            hex!("68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3"),
+            hex!("8c8a13df77a28f3445213a0f432fde644acaa215fc72dcdf300d5efaa85d350c"),
+        );
+        check(
+            &seed,
+            "m/0'/1'",
+            hex!("b1d0bad404bf35da785a64ca1ac54b2617211d2777696fbffaf208f746ae84f2"),
+            hex!("1932a5270f335bed617d5b935c80aedb1a35bd9fc1e31acafd5372c30f5c1187"),
+        );
+        check(
+            &seed,
+            "m/0'/1'/2'",
+            hex!("92a5b23c0b8a99e37d07df3fb9966917f5d06e02ddbd909c7e184371463e9fc9"),
+            hex!("ae98736566d30ed0e9d2f4486a64bc95740d89c7db33f52121f8ea8f76ff0fc1"),
+        );
+        check(
+            &seed,
+            "m/0H/1H/2H/2H",
+            hex!("30d1dc7e5fc04c31219ab25a27ae00b50f6fd66622f6e9c913253d6511d1e662"),
+            hex!("8abae2d66361c879b900d204ad2cc4984fa2aa344dd7ddc46007329ac76c429c"),
+        );
+        check(
+            &seed,
+            "m/0h/1h/2h/2h/1000000000h",
+            hex!("8f94d394a8e8fd6b1bc2f3f49f5c47e385281d5c17e65324b0f62483e37e8793"),
+            hex!("3c24da049451555d51a7014a37337aa4e12d41e485abccfa46b47dfb2af54b7a"),
+        );
+    }
+
+    #[test]
+    fn test_invalid_path() {
+        let seed = [0u8; 16];
+        for path in ["", "44'/397'", "m/", "m/x'", "m/2147483648'", "m//0'"] {
+            assert!(
+                matches!(derive_ed25519_path(&seed, path), Err(DeriveError::InvalidPath { .. })),
+                "{path}"
+            );
+        }
+        assert!(matches!(
+            derive_ed25519_path(&seed, "m/44'/397'/0"),
+            Err(DeriveError::NonHardenedIndex { index: 0 })
+        ));
+    }
+}
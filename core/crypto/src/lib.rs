@@ -1,12 +1,22 @@
 #![deny(clippy::arithmetic_side_effects)]
 
-pub use errors::{ParseKeyError, ParseKeyTypeError, ParseSignatureError};
+pub use digest::Digest32;
+pub use errors::{
+    DeriveError, DigestError, KeyConversionError, ParseKeyError, ParseKeyTypeError,
+    ParseRsaSchemeError, ParseSignatureError, RecoverError, SignerError, SigningError,
+    ValidationError, VerifyError, VerifyFailure, WifError,
+};
 pub use key_file::KeyFile;
+pub use multi_signature::MultiSignature;
+pub use shared_public_key::SharedPublicKey;
 pub use signature::{
-    ED25519PublicKey, ED25519SecretKey, KeyType, PublicKey, Rsa2048PublicKey, Rsa2048Signature,
-    Secp256K1PublicKey, Secp256K1Signature, SecretKey, Signature,
+    public_key_from_secret_bytes, AddressScheme, ED25519PublicKey, ED25519SecretKey, KeyType,
+    P256PublicKey, P256Signature, PublicKey, Rsa2048PublicKey, Rsa2048Signature, RsaPssHash,
+    RsaScheme, RsaSignature, Secp256K1PublicKey, Secp256K1Signature, SecretKey, Signature,
 };
 pub use signer::{EmptySigner, InMemorySigner, Signer};
+pub use verifier::SignatureVerifier;
+pub use verify_cost::{Gas, VerifyCost, VerifyCostTable};
 
 #[macro_use]
 mod hash;
@@ -15,10 +25,19 @@ mod traits;
 #[macro_use]
 mod util;
 
+pub mod consts;
+mod digest;
 mod errors;
+pub mod hd;
 pub mod key_conversion;
 mod key_file;
+mod multi_signature;
+mod shared_public_key;
 mod signature;
 mod signer;
+#[cfg(feature = "ssh")]
+mod ssh;
 mod test_utils;
+mod verifier;
+mod verify_cost;
 pub mod vrf;
@@ -0,0 +1,105 @@
+use crate::{PublicKey, Signature};
+use borsh::{BorshDeserialize, BorshSerialize};
+use std::collections::HashSet;
+
+/// Collection of signatures over the same message made by different signers.
+///
+/// This is not signature aggregation (e.g. BLS), the signatures are stored and
+/// verified one by one.
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    BorshSerialize,
+    BorshDeserialize,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub struct MultiSignature {
+    pub sigs: Vec<(PublicKey, Signature)>,
+}
+
+impl MultiSignature {
+    pub fn new(sigs: Vec<(PublicKey, Signature)>) -> Self {
+        Self { sigs }
+    }
+
+    /// Returns whether every signature in the collection verifies `data`.
+    /// Returns `true` for an empty collection.
+    pub fn verify_all(&self, data: &[u8]) -> bool {
+        self.sigs.iter().all(|(public_key, signature)| signature.verify(data, public_key))
+    }
+
+    /// Returns whether at least `threshold` distinct signers have signed
+    /// `data`.  Invalid signatures are ignored and a signer with several valid
+    /// signatures is counted once.
+    pub fn verify_threshold(&self, data: &[u8], threshold: usize) -> bool {
+        if threshold == 0 {
+            return true;
+        }
+        let mut signers = HashSet::new();
+        for (public_key, signature) in &self.sigs {
+            if !signers.contains(public_key) && signature.verify(data, public_key) {
+                signers.insert(public_key);
+                if signers.len() >= threshold {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{KeyType, SecretKey};
+
+    #[test]
+    fn test_verify_threshold() {
+        let data = b"123";
+        let signers: Vec<SecretKey> = ["a", "b", "c"]
+            .into_iter()
+            .map(|seed| SecretKey::from_seed(KeyType::ED25519, seed))
+            .collect();
+        let mut multi_signature = MultiSignature::new(
+            signers.iter().map(|signer| (signer.public_key(), signer.sign(data))).collect(),
+        );
+        assert!(multi_signature.verify_all(data));
+        assert!(multi_signature.verify_threshold(data, 3));
+        assert!(!multi_signature.verify_threshold(data, 4));
+        assert!(!multi_signature.verify_all(b"456"));
+        assert!(!multi_signature.verify_threshold(b"456", 1));
+        assert!(multi_signature.verify_threshold(b"456", 0));
+
+        // Invalid signature doesn't count towards the threshold.
+        multi_signature.sigs[2].1 = signers[0].sign(data);
+        assert!(!multi_signature.verify_all(data));
+        assert!(multi_signature.verify_threshold(data, 2));
+        assert!(!multi_signature.verify_threshold(data, 3));
+
+        // Neither does the same signer appearing twice.
+        multi_signature.sigs[2] = multi_signature.sigs[0].clone();
+        assert!(multi_signature.verify_all(data));
+        assert!(multi_signature.verify_threshold(data, 2));
+        assert!(!multi_signature.verify_threshold(data, 3));
+
+        let empty = MultiSignature::default();
+        assert!(empty.verify_all(data));
+        assert!(empty.verify_threshold(data, 0));
+        assert!(!empty.verify_threshold(data, 1));
+    }
+
+    #[test]
+    fn test_serialization() {
+        let signer = SecretKey::from_seed(KeyType::SECP256K1, "test");
+        let data = [7u8; 32];
+        let multi_signature = MultiSignature::new(vec![(signer.public_key(), signer.sign(&data))]);
+        let bytes = borsh::to_vec(&multi_signature).unwrap();
+        assert_eq!(MultiSignature::try_from_slice(&bytes).unwrap(), multi_signature);
+        let json = serde_json::to_string(&multi_signature).unwrap();
+        assert_eq!(serde_json::from_str::<MultiSignature>(&json).unwrap(), multi_signature);
+    }
+}
@@ -0,0 +1,96 @@
+use crate::PublicKey;
+use std::fmt::{Debug, Display, Formatter};
+use std::ops::Deref;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Reference-counted [`PublicKey`] which is cheap to clone.
+///
+/// Cloning a `PublicKey` copies the key data (and allocates for RSA keys);
+/// cloning this only bumps the reference count.  Meant for hot paths which
+/// clone the same keys over and over, e.g. routing tables.  Equality, hashing
+/// and ordering are those of the underlying key.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SharedPublicKey(Arc<PublicKey>);
+
+impl SharedPublicKey {
+    pub fn new(public_key: PublicKey) -> Self {
+        Self(Arc::new(public_key))
+    }
+}
+
+impl Deref for SharedPublicKey {
+    type Target = PublicKey;
+
+    fn deref(&self) -> &PublicKey {
+        &self.0
+    }
+}
+
+impl AsRef<PublicKey> for SharedPublicKey {
+    fn as_ref(&self) -> &PublicKey {
+        &self.0
+    }
+}
+
+impl From<PublicKey> for SharedPublicKey {
+    fn from(public_key: PublicKey) -> Self {
+        Self::new(public_key)
+    }
+}
+
+impl From<&SharedPublicKey> for PublicKey {
+    fn from(public_key: &SharedPublicKey) -> Self {
+        PublicKey::clone(public_key)
+    }
+}
+
+impl Display for SharedPublicKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&*self.0, f)
+    }
+}
+
+impl Debug for SharedPublicKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&*self.0, f)
+    }
+}
+
+impl FromStr for SharedPublicKey {
+    type Err = crate::errors::ParseKeyError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        PublicKey::from_str(value).map(Self::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::KeyType;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_shared_public_key() {
+        let public_key = PublicKey::from_seed(KeyType::RSA2048, "test");
+        let shared = SharedPublicKey::from(public_key.clone());
+        let cloned = shared.clone();
+        assert!(Arc::ptr_eq(&shared.0, &cloned.0));
+        assert_eq!(*cloned, public_key);
+        assert_eq!(cloned.key_type() as u8, KeyType::RSA2048 as u8);
+        assert_eq!(PublicKey::from(&cloned), public_key);
+
+        let value = public_key.to_string();
+        assert_eq!(shared.to_string(), value);
+        assert_eq!(format!("{shared:?}"), format!("{public_key:?}"));
+        assert_eq!(SharedPublicKey::from_str(&value).unwrap(), shared);
+        assert!(SharedPublicKey::from_str("ed25519:0").is_err());
+
+        let other = SharedPublicKey::new(PublicKey::from_seed(KeyType::ED25519, "test"));
+        assert_ne!(shared, other);
+        assert!(other < shared);
+        let set: HashSet<_> = [shared, cloned, other].into_iter().collect();
+        assert_eq!(set.len(), 2);
+    }
+}
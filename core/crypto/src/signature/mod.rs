@@ -0,0 +1,3530 @@
+use crate::digest::Digest32;
+use borsh::{BorshDeserialize, BorshSerialize};
+use ed25519_dalek::ed25519::signature::{Signer, Verifier};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use rsa::pkcs8::{DecodePrivateKey, DecodePublicKey, EncodePrivateKey, EncodePublicKey};
+use rsa::Pkcs1v15Sign;
+use secp256k1::rand::rngs::OsRng;
+use std::convert::AsRef;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::io::{Error, ErrorKind, Read, Write};
+use std::str::FromStr;
+use zeroize::Zeroizing;
+
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(test, derive(bolero::TypeGenerator))]
+pub enum KeyType {
+    ED25519 = 0,
+    SECP256K1 = 1,
+    RSA2048 = 2,
+    P256 = 3,
+}
+
+impl KeyType {
+    /// Returns the length of the raw public key data of this type, i.e. the
+    /// length of [`PublicKey::key_data`].
+    pub fn public_key_len(&self) -> usize {
+        match self {
+            KeyType::ED25519 => ed25519_dalek::PUBLIC_KEY_LENGTH,
+            KeyType::SECP256K1 => PUBLIC_KEY_SECP256K1_LENGTH,
+            KeyType::RSA2048 => RAW_PUBLIC_KEY_RSA_2048_LENGTH,
+            KeyType::P256 => PUBLIC_KEY_P256_LENGTH,
+        }
+    }
+
+    /// Returns the length of the raw signature data of this type, or `None` if
+    /// signatures of the type don't have a fixed length.  All currently
+    /// supported types have fixed-length signatures.
+    pub fn signature_len(&self) -> Option<usize> {
+        match self {
+            KeyType::ED25519 => Some(ed25519_dalek::SIGNATURE_LENGTH),
+            KeyType::SECP256K1 => Some(SECP256K1_SIGNATURE_LENGTH),
+            KeyType::RSA2048 => Some(RSA2048_SIGNATURE_LENGTH),
+            KeyType::P256 => Some(P256_SIGNATURE_LENGTH),
+        }
+    }
+}
+
+/// The default key type is ED25519.  It's what strings without a key type
+/// prefix are parsed as (see [`PublicKey`]) and what placeholder values such
+/// as `Signature::default()` use; those refer to this impl so that the
+/// default is defined in a single place.
+impl Default for KeyType {
+    fn default() -> Self {
+        KeyType::ED25519
+    }
+}
+
+impl Display for KeyType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        f.write_str(match self {
+            KeyType::ED25519 => "ed25519",
+            KeyType::SECP256K1 => "secp256k1",
+            KeyType::RSA2048 => "rsa2048",
+            KeyType::P256 => "p256",
+        })
+    }
+}
+
+impl FromStr for KeyType {
+    type Err = crate::errors::ParseKeyTypeError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let lowercase_key_type = value.to_ascii_lowercase();
+        match lowercase_key_type.as_str() {
+            "ed25519" => Ok(KeyType::ED25519),
+            "secp256k1" => Ok(KeyType::SECP256K1),
+            "rsa2048" => Ok(KeyType::RSA2048),
+            "p256" => Ok(KeyType::P256),
+            _ => Err(Self::Err::UnknownKeyType { unknown_key_type: lowercase_key_type }),
+        }
+    }
+}
+
+/// Serializes as the lowercase string form, same as the `keytype:` prefix of
+/// keys and signatures.  Deserialization is case-insensitive so the uppercase
+/// variant names produced by earlier versions are still accepted.
+impl serde::Serialize for KeyType {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> Result<<S as serde::Serializer>::Ok, <S as serde::Serializer>::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for KeyType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, <D as serde::Deserializer<'de>>::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(|err| serde::de::Error::custom(err.to_string()))
+    }
+}
+
+impl TryFrom<u8> for KeyType {
+    type Error = crate::errors::ParseKeyTypeError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0_u8 => Ok(KeyType::ED25519),
+            1_u8 => Ok(KeyType::SECP256K1),
+            2_u8 => Ok(KeyType::RSA2048),
+            3_u8 => Ok(KeyType::P256),
+            unknown_key_type => {
+                Err(Self::Error::UnknownKeyType { unknown_key_type: unknown_key_type.to_string() })
+            }
+        }
+    }
+}
+
+/// Returns whether `value` starts with an explicit `keytype:` prefix.  Used by
+/// the `from_str_strict` parsers which don't default to ED25519.  An empty
+/// prefix such as in `":data"` doesn't count.
+fn has_key_type_prefix(value: &str) -> bool {
+    value.split_once(':').is_some_and(|(prefix, _)| !prefix.trim().is_empty())
+}
+
+/// Computes the message signed by [`SecretKey::sign_with_context`]:
+/// `sha256(len(context) || context || data)` where `len(context)` is the
+/// length of the context as a little-endian u32 (same framing as Borsh uses for
+/// byte vectors).  Fails if the context length doesn't fit in a u32.
+fn context_digest(context: &[u8], data: &[u8]) -> Result<[u8; 32], crate::errors::SigningError> {
+    use sha2::Digest;
+    let context_len =
+        u32::try_from(context.len()).map_err(|_| crate::errors::SigningError::ContextTooLong {
+            max: u32::MAX as usize,
+            received: context.len(),
+        })?;
+    Ok(sha2::Sha256::new()
+        .chain_update(context_len.to_le_bytes())
+        .chain_update(context)
+        .chain_update(data)
+        .finalize()
+        .into())
+}
+
+/// Regular expression matching the canonical `keytype:base58` string form of
+/// keys, see e.g. [`PublicKey::schema_pattern`].
+const KEY_SCHEMA_PATTERN: &str = "^(ed25519|secp256k1|rsa2048|p256):[1-9A-HJ-NP-Za-km-z]+$";
+
+/// Optional scheme accepted by [`PublicKey::from_uri`].
+const KEY_URI_SCHEME: &str = "key:";
+
+/// Splits `keytype:data` string into the key type and the data.
+///
+/// The input is parsed leniently to accept e.g. user-pasted strings:
+/// - the key type is matched case-insensitively, e.g. `ED25519:` is accepted;
+/// - whitespace around the key type and around the data is ignored, e.g.
+///   ` ed25519 : data ` is accepted (whitespace inside the data isn't);
+/// - if there's no `:` the whole (trimmed) input is data of the
+///   [default](KeyType::default) key type, i.e. ED25519.
+fn split_key_type_data(value: &str) -> Result<(KeyType, &str), crate::errors::ParseKeyTypeError> {
+    if let Some((prefix, key_data)) = value.split_once(':') {
+        Ok((KeyType::from_str(prefix.trim())?, key_data.trim()))
+    } else {
+        // If there is no prefix then we default to ED25519.
+        Ok((KeyType::default(), value.trim()))
+    }
+}
+
+/// Implements `Display`, `FromStr` and serde traits for a fixed-size key or
+/// signature newtype.  The value is represented as a bare base58 string, i.e.
+/// without the key type prefix, and serialized with the same scheme as
+/// [`PublicKey`] and [`Signature`]: as that string in human-readable formats
+/// and as the raw (Borsh) bytes in binary formats.
+macro_rules! impl_bs58_serde {
+    ($ty:ty, $len:expr, $error:ty) => {
+        impl Display for $ty {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                Display::fmt(&Bs58(&self.0), f)
+            }
+        }
+
+        impl FromStr for $ty {
+            type Err = $error;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                Self::try_from(&decode_bs58::<{ $len }>(value)?[..])
+            }
+        }
+
+        impl serde::Serialize for $ty {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serialize_str_or_bytes(self, serializer)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $ty {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserialize_str_or_bytes(deserializer)
+            }
+        }
+    };
+}
+
+/// Implements infallible `from_array` constructor for a fixed-size key or
+/// signature newtype.
+macro_rules! impl_from_array {
+    ($ty:ty, $len:expr) => {
+        impl $ty {
+            /// Constructs the value from raw data of the exact length.  Unlike
+            /// `TryFrom<&[u8]>` this can't fail.
+            pub const fn from_array(data: [u8; $len]) -> Self {
+                Self(data)
+            }
+        }
+    };
+}
+
+// The per-scheme modules use the macros above so they have to be declared
+// after them.
+mod p256_ecdsa;
+mod rsa2048;
+mod secp256k1_ecdsa;
+
+use self::p256_ecdsa::{
+    p256_signature, parse_p256_signature, P256_GENERATOR, P256_SECRET_KEY_LENGTH,
+};
+pub use self::p256_ecdsa::{P256PublicKey, P256Signature};
+pub(crate) use self::p256_ecdsa::{P256_SIGNATURE_LENGTH, PUBLIC_KEY_P256_LENGTH};
+pub use self::rsa2048::{Rsa2048PublicKey, Rsa2048Signature, RsaPssHash, RsaScheme, RsaSignature};
+pub(crate) use self::rsa2048::{RAW_PUBLIC_KEY_RSA_2048_LENGTH, RSA2048_SIGNATURE_LENGTH};
+use self::secp256k1_ecdsa::{secp256k1_verifier, sign_secp256k1};
+pub use self::secp256k1_ecdsa::{Secp256K1PublicKey, Secp256K1Signature, SECP256K1};
+pub(crate) use self::secp256k1_ecdsa::{PUBLIC_KEY_SECP256K1_LENGTH, SECP256K1_SIGNATURE_LENGTH};
+
+#[derive(
+    Clone,
+    Eq,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    derive_more::AsRef,
+    derive_more::From,
+    BorshSerialize,
+    BorshDeserialize,
+)]
+#[cfg_attr(test, derive(bolero::TypeGenerator))]
+#[as_ref(forward)]
+pub struct ED25519PublicKey(pub [u8; ed25519_dalek::PUBLIC_KEY_LENGTH]);
+
+impl TryFrom<&[u8]> for ED25519PublicKey {
+    type Error = crate::errors::ParseKeyError;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        data.try_into().map(Self).map_err(|_| Self::Error::InvalidLength {
+            expected_length: ed25519_dalek::PUBLIC_KEY_LENGTH,
+            received_length: data.len(),
+        })
+    }
+}
+
+impl std::fmt::Debug for ED25519PublicKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        Display::fmt(&Bs58(&self.0), f)
+    }
+}
+
+impl_bs58_serde!(ED25519PublicKey, ed25519_dalek::PUBLIC_KEY_LENGTH, crate::errors::ParseKeyError);
+impl_from_array!(ED25519PublicKey, ed25519_dalek::PUBLIC_KEY_LENGTH);
+
+/// Fails if the bytes aren't a valid point on the curve.
+impl TryFrom<&ED25519PublicKey> for ed25519_dalek::VerifyingKey {
+    type Error = crate::errors::ParseKeyError;
+
+    fn try_from(public_key: &ED25519PublicKey) -> Result<Self, Self::Error> {
+        ed25519_dalek::VerifyingKey::from_bytes(&public_key.0)
+            .map_err(|err| Self::Error::InvalidData { error_message: err.to_string() })
+    }
+}
+
+impl From<&ed25519_dalek::VerifyingKey> for ED25519PublicKey {
+    fn from(public_key: &ed25519_dalek::VerifyingKey) -> Self {
+        Self(public_key.to_bytes())
+    }
+}
+
+/// Scheme used to derive a short account address from a public key.
+///
+/// Only the Ethereum scheme is supported for now.  Other schemes, e.g.
+/// Bitcoin's `ripemd160(sha256(key))`, may be added in the future.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AddressScheme {
+    /// Last 20 bytes of keccak256 of the 64-byte uncompressed secp256k1 key.
+    Ethereum,
+}
+
+/// Public key container supporting different curves.
+///
+/// Keys are totally ordered by key type first and then by the key data
+/// (`(key_type as u8, key_data())`).  The order depends on the key value only
+/// so it's preserved across serialization round-trips.
+///
+/// The string form used by `Display`, `FromStr` and serde is
+/// `keytype:base58`.  When parsing, a bare base58 string without the key type
+/// prefix is taken to be an ED25519 key.  [`SecretKey`] and [`Signature`]
+/// parse untagged strings the same way.
+#[derive(Clone, PartialEq, PartialOrd, Ord, Eq)]
+#[cfg_attr(test, derive(bolero::TypeGenerator))]
+pub enum PublicKey {
+    /// 256 bit elliptic curve based public-key.
+    ED25519(ED25519PublicKey),
+    /// 512 bit elliptic curve based public-key used in Bitcoin's public-key cryptography.
+    SECP256K1(Secp256K1PublicKey),
+    /// 2048 bit rsa
+    RSA(Box<Rsa2048PublicKey>),
+    /// 512 bit NIST P-256 (secp256r1) elliptic curve based public-key used by HSMs and WebAuthn.
+    P256(P256PublicKey),
+}
+
+impl PublicKey {
+    // `is_empty` always returns false, so there is no point in adding it
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        const ED25519_LEN: usize = ed25519_dalek::PUBLIC_KEY_LENGTH + 1;
+        match self {
+            Self::ED25519(_) => ED25519_LEN,
+            Self::SECP256K1(_) => PUBLIC_KEY_SECP256K1_LENGTH + 1,
+            Self::RSA(_) => RAW_PUBLIC_KEY_RSA_2048_LENGTH + 1,
+            Self::P256(_) => PUBLIC_KEY_P256_LENGTH + 1,
+        }
+    }
+
+    /// Returns length of the borsh encoding of the key, i.e. the key type tag
+    /// followed by the key data, without serializing it.  Same as
+    /// [`PublicKey::len`].
+    pub fn borsh_len(&self) -> usize {
+        self.len()
+    }
+
+    pub fn empty(key_type: KeyType) -> Self {
+        match key_type {
+            KeyType::ED25519 => {
+                PublicKey::ED25519(ED25519PublicKey([0u8; ed25519_dalek::PUBLIC_KEY_LENGTH]))
+            }
+            KeyType::SECP256K1 => {
+                PublicKey::SECP256K1(Secp256K1PublicKey([0u8; PUBLIC_KEY_SECP256K1_LENGTH]))
+            }
+            KeyType::RSA2048 => {
+                PublicKey::RSA(Box::new(Rsa2048PublicKey([0u8; RAW_PUBLIC_KEY_RSA_2048_LENGTH])))
+            }
+            KeyType::P256 => PublicKey::P256(P256PublicKey(P256_GENERATOR)),
+        }
+    }
+
+    /// Returns whether the key is a placeholder created by [`PublicKey::empty`]
+    /// rather than a real key, i.e. all bytes are zero or, for P-256, it's the
+    /// generator point.  Signatures never verify against such a key.
+    pub fn is_zero(&self) -> bool {
+        match self {
+            PublicKey::P256(public_key) => public_key.0 == P256_GENERATOR,
+            _ => self.key_data().iter().all(|byte| *byte == 0),
+        }
+    }
+
+    pub fn key_type(&self) -> KeyType {
+        match self {
+            Self::ED25519(_) => KeyType::ED25519,
+            Self::SECP256K1(_) => KeyType::SECP256K1,
+            Self::RSA(_) => KeyType::RSA2048,
+            Self::P256(_) => KeyType::P256,
+        }
+    }
+
+    pub fn key_data(&self) -> &[u8] {
+        match self {
+            Self::ED25519(key) => key.as_ref(),
+            Self::SECP256K1(key) => key.as_ref(),
+            Self::RSA(key) => key.as_ref().as_ref(),
+            Self::P256(key) => key.as_ref(),
+        }
+    }
+
+    /// Compares only the raw key bytes, ignoring the key type, unlike `==`
+    /// which also requires the types to match.
+    ///
+    /// Meant for interop edge cases where the same bytes arrive tagged with
+    /// different key types.  Equal data does *not* mean the keys are
+    /// cryptographically equivalent: the bytes are interpreted differently by
+    /// each curve.
+    pub fn key_data_eq(&self, other: &PublicKey) -> bool {
+        self.key_data() == other.key_data()
+    }
+
+    /// Derives a 20-byte address from the key using the given scheme.  Returns
+    /// `None` if the scheme isn't defined for the key type, e.g. Ethereum
+    /// addresses are derived from secp256k1 keys only.
+    pub fn to_address(&self, scheme: AddressScheme) -> Option<[u8; 20]> {
+        use sha3::Digest;
+        match (scheme, self) {
+            (AddressScheme::Ethereum, Self::SECP256K1(key)) => {
+                let hash = sha3::Keccak256::digest(key.0);
+                let mut address = [0; 20];
+                address.copy_from_slice(&hash[12..]);
+                Some(address)
+            }
+            (AddressScheme::Ethereum, _) => None,
+        }
+    }
+
+    pub fn unwrap_as_ed25519(&self) -> &ED25519PublicKey {
+        match self {
+            Self::ED25519(key) => key,
+            _ => panic!(),
+        }
+    }
+
+    pub fn unwrap_as_secp256k1(&self) -> &Secp256K1PublicKey {
+        match self {
+            Self::SECP256K1(key) => key,
+            _ => panic!(),
+        }
+    }
+
+    pub fn unwrap_as_rsa2048(&self) -> &Rsa2048PublicKey {
+        match self {
+            Self::RSA(key) => key,
+            _ => panic!(),
+        }
+    }
+
+    pub fn unwrap_as_p256(&self) -> &P256PublicKey {
+        match self {
+            Self::P256(key) => key,
+            _ => panic!(),
+        }
+    }
+
+    /// Returns regular expression matching the `keytype:base58` string form the
+    /// key is serialized as, e.g. for use as `pattern` in JSON Schema.
+    ///
+    /// Parsing additionally accepts a key without the prefix as ED25519 but
+    /// that form is never produced.
+    pub fn schema_pattern() -> &'static str {
+        KEY_SCHEMA_PATTERN
+    }
+
+    /// Same as [`PublicKey::from_str`] but requires an explicit `keytype:`
+    /// prefix rather than defaulting to ED25519 when it's missing.
+    pub fn from_str_strict(value: &str) -> Result<Self, crate::errors::ParseKeyError> {
+        if !has_key_type_prefix(value) {
+            return Err(crate::errors::ParseKeyError::MissingKeyTypePrefix);
+        }
+        Self::from_str(value)
+    }
+
+    /// Parses the key from a URI-like string, i.e. its regular string form
+    /// optionally prefixed with the `key:` scheme, e.g.
+    /// `key:ed25519:<base58>`.
+    ///
+    /// Any other scheme (anything before the first `:` which isn't a key type)
+    /// is rejected with [`ParseKeyError::UnknownUriScheme`].
+    ///
+    /// [`ParseKeyError::UnknownUriScheme`]: crate::errors::ParseKeyError::UnknownUriScheme
+    pub fn from_uri(value: &str) -> Result<Self, crate::errors::ParseKeyError> {
+        let value = value.trim();
+        let value = value.strip_prefix(KEY_URI_SCHEME).unwrap_or(value);
+        if let Some((scheme, _)) = value.split_once(':') {
+            if KeyType::from_str(scheme.trim()).is_err() {
+                return Err(crate::errors::ParseKeyError::UnknownUriScheme {
+                    unknown_scheme: scheme.to_string(),
+                });
+            }
+        }
+        Self::from_str(value)
+    }
+
+    /// Parses `signature` in its `keytype:base58` string form and verifies it
+    /// against `data` with this key.
+    pub fn verify_str(
+        &self,
+        data: &[u8],
+        signature: &str,
+    ) -> Result<bool, crate::errors::ParseSignatureError> {
+        Ok(Signature::from_str(signature)?.verify(data, self))
+    }
+
+    /// Returns multi-line human-readable description of the key listing its
+    /// type, string form, hex encoding of the key data and its length.
+    ///
+    /// Meant for diagnosing key mismatches; the format isn't stable and isn't
+    /// meant to be parsed, use `Display` for that.
+    pub fn debug_repr(&self) -> String {
+        let key_data = self.key_data();
+        format!(
+            "key type: {}\nbase58: {}\nhex: {}\nlength: {} bytes",
+            self.key_type(),
+            self,
+            hex::encode(key_data),
+            key_data.len()
+        )
+    }
+
+    /// Verifies several signatures made with this key, each over its own
+    /// data.  Returns `true` only if every signature verifies, stopping at the
+    /// first one which doesn't.
+    ///
+    /// Signatures are currently checked one by one; ED25519 batch
+    /// verification may be used here in the future without changing the
+    /// result.
+    pub fn verify_many(&self, items: &[(&[u8], &Signature)]) -> bool {
+        items.iter().all(|(data, signature)| signature.verify(data, self))
+    }
+
+    /// Returns the string form of the key with the data encoded in
+    /// base58check rather than plain base58, i.e. followed by a four-byte
+    /// double SHA-256 checksum so that typos are detected when the key is
+    /// parsed back with [`PublicKey::from_base58check`].
+    pub fn to_base58check(&self) -> String {
+        bs58_to_base58check(&self.to_string())
+    }
+
+    /// Parses key written by [`PublicKey::to_base58check`].  Returns
+    /// [`ParseKeyError::ChecksumMismatch`](crate::errors::ParseKeyError::ChecksumMismatch)
+    /// if the checksum doesn't match the data.
+    pub fn from_base58check(value: &str) -> Result<Self, crate::errors::ParseKeyError> {
+        Self::from_str(&base58check_to_bs58(value)?)
+    }
+}
+
+// This `Hash` implementation is safe since it retains the property
+// `k1 == k2 ⇒ hash(k1) == hash(k2)`.
+impl Hash for PublicKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            PublicKey::ED25519(public_key) => {
+                state.write_u8(0u8);
+                state.write(&public_key.0);
+            }
+            PublicKey::SECP256K1(public_key) => {
+                state.write_u8(1u8);
+                state.write(&public_key.0);
+            }
+            PublicKey::RSA(public_key) => {
+                state.write_u8(2u8);
+                state.write(&public_key.0);
+            }
+            PublicKey::P256(public_key) => {
+                state.write_u8(3u8);
+                state.write(&public_key.0);
+            }
+        }
+    }
+}
+
+impl Display for PublicKey {
+    fn fmt(&self, fmt: &mut Formatter) -> std::fmt::Result {
+        let (key_type, key_data) = match self {
+            PublicKey::ED25519(public_key) => (KeyType::ED25519, &public_key.0[..]),
+            PublicKey::SECP256K1(public_key) => (KeyType::SECP256K1, &public_key.0[..]),
+            PublicKey::RSA(public_key) => (KeyType::RSA2048, &public_key.0[..]),
+            PublicKey::P256(public_key) => (KeyType::P256, &public_key.0[..]),
+        };
+        write!(fmt, "{}:{}", key_type, Bs58(key_data))
+    }
+}
+
+impl Debug for PublicKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        Display::fmt(self, f)
+    }
+}
+
+impl BorshSerialize for PublicKey {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
+        match self {
+            PublicKey::ED25519(public_key) => {
+                BorshSerialize::serialize(&0u8, writer)?;
+                writer.write_all(&public_key.0)?;
+            }
+            PublicKey::SECP256K1(public_key) => {
+                BorshSerialize::serialize(&1u8, writer)?;
+                writer.write_all(&public_key.0)?;
+            }
+            PublicKey::RSA(public_key) => {
+                BorshSerialize::serialize(&2u8, writer)?;
+                writer.write_all(&public_key.0)?;
+            }
+            PublicKey::P256(public_key) => {
+                BorshSerialize::serialize(&3u8, writer)?;
+                writer.write_all(&public_key.0)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl BorshDeserialize for PublicKey {
+    fn deserialize_reader<R: Read>(rd: &mut R) -> std::io::Result<Self> {
+        let key_type = KeyType::try_from(u8::deserialize_reader(rd)?)
+            .map_err(|err| Error::new(ErrorKind::InvalidData, err.to_string()))?;
+        match key_type {
+            KeyType::ED25519 => {
+                Ok(PublicKey::ED25519(ED25519PublicKey(BorshDeserialize::deserialize_reader(rd)?)))
+            }
+            KeyType::SECP256K1 => Ok(PublicKey::SECP256K1(Secp256K1PublicKey(
+                BorshDeserialize::deserialize_reader(rd)?,
+            ))),
+            KeyType::RSA2048 => Ok(PublicKey::RSA(Box::new(Rsa2048PublicKey(
+                BorshDeserialize::deserialize_reader(rd)?,
+            )))),
+            KeyType::P256 => Ok(PublicKey::P256(BorshDeserialize::deserialize_reader(rd)?)),
+        }
+    }
+}
+
+/// Serializes the public key as the `keytype:base58` string in human-readable
+/// formats (e.g. JSON) and as the tagged raw bytes (same as Borsh) in binary
+/// formats (e.g. bincode).
+impl serde::Serialize for PublicKey {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> Result<<S as serde::Serializer>::Ok, <S as serde::Serializer>::Error>
+    where
+        S: serde::Serializer,
+    {
+        serialize_str_or_bytes(self, serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for PublicKey {
+    fn deserialize<D>(deserializer: D) -> Result<Self, <D as serde::Deserializer<'de>>::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserialize_str_or_bytes(deserializer)
+    }
+}
+
+impl FromStr for PublicKey {
+    type Err = crate::errors::ParseKeyError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (key_type, key_data) = split_key_type_data(value)?;
+        Ok(match key_type {
+            KeyType::ED25519 => Self::ED25519(ED25519PublicKey(decode_bs58(key_data)?)),
+            KeyType::SECP256K1 => Self::SECP256K1(Secp256K1PublicKey(decode_bs58(key_data)?)),
+            KeyType::RSA2048 => Self::RSA(Box::new(Rsa2048PublicKey(decode_bs58(key_data)?))),
+            KeyType::P256 => Self::P256(P256PublicKey::from_point(decode_bs58(key_data)?)?),
+        })
+    }
+}
+
+impl From<ED25519PublicKey> for PublicKey {
+    fn from(ed25519: ED25519PublicKey) -> Self {
+        Self::ED25519(ed25519)
+    }
+}
+
+impl From<Secp256K1PublicKey> for PublicKey {
+    fn from(secp256k1: Secp256K1PublicKey) -> Self {
+        Self::SECP256K1(secp256k1)
+    }
+}
+
+impl From<Rsa2048PublicKey> for PublicKey {
+    fn from(rsa2048: Rsa2048PublicKey) -> Self {
+        Self::RSA(Box::new(rsa2048))
+    }
+}
+
+impl From<P256PublicKey> for PublicKey {
+    fn from(p256: P256PublicKey) -> Self {
+        Self::P256(p256)
+    }
+}
+
+fn key_type_mismatch(
+    expected_key_type: KeyType,
+    received_key_type: KeyType,
+) -> crate::errors::KeyConversionError {
+    crate::errors::KeyConversionError::KeyTypeMismatch { expected_key_type, received_key_type }
+}
+
+/// Implements fallible extraction of a concrete public key out of
+/// [`PublicKey`], i.e. non-panicking counterparts of `unwrap_as_*`.
+macro_rules! impl_try_from_public_key {
+    ($ty:ty, $variant:ident, $key_type:expr) => {
+        impl TryFrom<PublicKey> for $ty {
+            type Error = crate::errors::KeyConversionError;
+
+            fn try_from(public_key: PublicKey) -> Result<Self, Self::Error> {
+                match public_key {
+                    PublicKey::$variant(key) => Ok(key),
+                    other => Err(key_type_mismatch($key_type, other.key_type())),
+                }
+            }
+        }
+
+        impl<'a> TryFrom<&'a PublicKey> for &'a $ty {
+            type Error = crate::errors::KeyConversionError;
+
+            fn try_from(public_key: &'a PublicKey) -> Result<Self, Self::Error> {
+                match public_key {
+                    PublicKey::$variant(key) => Ok(key),
+                    other => Err(key_type_mismatch($key_type, other.key_type())),
+                }
+            }
+        }
+    };
+}
+
+impl_try_from_public_key!(ED25519PublicKey, ED25519, KeyType::ED25519);
+impl_try_from_public_key!(Secp256K1PublicKey, SECP256K1, KeyType::SECP256K1);
+impl_try_from_public_key!(P256PublicKey, P256, KeyType::P256);
+
+impl TryFrom<PublicKey> for Rsa2048PublicKey {
+    type Error = crate::errors::KeyConversionError;
+
+    fn try_from(public_key: PublicKey) -> Result<Self, Self::Error> {
+        match public_key {
+            PublicKey::RSA(key) => Ok(*key),
+            other => Err(key_type_mismatch(KeyType::RSA2048, other.key_type())),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a PublicKey> for &'a Rsa2048PublicKey {
+    type Error = crate::errors::KeyConversionError;
+
+    fn try_from(public_key: &'a PublicKey) -> Result<Self, Self::Error> {
+        match public_key {
+            PublicKey::RSA(key) => Ok(key),
+            other => Err(key_type_mismatch(KeyType::RSA2048, other.key_type())),
+        }
+    }
+}
+
+#[derive(Clone, Eq)]
+// This is actually a keypair, because ed25519_dalek api only has keypair.sign
+// From ed25519_dalek doc: The first SECRET_KEY_LENGTH of bytes is the SecretKey
+// The last PUBLIC_KEY_LENGTH of bytes is the public key, in total it's KEYPAIR_LENGTH
+pub struct ED25519SecretKey(pub [u8; ed25519_dalek::KEYPAIR_LENGTH]);
+
+impl ED25519SecretKey {
+    /// Returns the 32-byte seed, i.e. the secret half of the keypair.  The
+    /// seed alone is enough to store the key compactly; restore it with
+    /// [`ED25519SecretKey::from_seed_bytes`].
+    pub fn seed(&self) -> [u8; ed25519_dalek::SECRET_KEY_LENGTH] {
+        let mut seed = [0; ed25519_dalek::SECRET_KEY_LENGTH];
+        seed.copy_from_slice(&self.0[..ed25519_dalek::SECRET_KEY_LENGTH]);
+        seed
+    }
+
+    /// Constructs the key from its 32-byte seed deriving the public half.
+    ///
+    /// Deriving the public key costs a SHA-512 hash and a scalar
+    /// multiplication, i.e. roughly as much as a signature.  Hence keys used
+    /// for signing repeatedly should be expanded once and kept in this full
+    /// form rather than re-created from the seed before every signature.
+    pub fn from_seed_bytes(seed: &[u8; ed25519_dalek::SECRET_KEY_LENGTH]) -> Self {
+        Self(ed25519_dalek::SigningKey::from_bytes(seed).to_keypair_bytes())
+    }
+}
+
+// Compares in constant time so that comparing against a secret doesn't leak
+// its prefix through timing.
+impl PartialEq for ED25519SecretKey {
+    fn eq(&self, other: &Self) -> bool {
+        use subtle::ConstantTimeEq;
+        self.0[..ed25519_dalek::SECRET_KEY_LENGTH]
+            .ct_eq(&other.0[..ed25519_dalek::SECRET_KEY_LENGTH])
+            .into()
+    }
+}
+
+impl std::fmt::Debug for ED25519SecretKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        Display::fmt(&Bs58(&self.0[..ed25519_dalek::SECRET_KEY_LENGTH]), f)
+    }
+}
+
+/// Only the secret half of the keypair bytes is used and the public half is
+/// derived from it, in line with how [`PartialEq`] compares the keys.
+impl From<&ED25519SecretKey> for ed25519_dalek::SigningKey {
+    fn from(secret_key: &ED25519SecretKey) -> Self {
+        let mut bytes = [0; ed25519_dalek::SECRET_KEY_LENGTH];
+        bytes.copy_from_slice(&secret_key.0[..ed25519_dalek::SECRET_KEY_LENGTH]);
+        ed25519_dalek::SigningKey::from_bytes(&bytes)
+    }
+}
+
+impl From<&ed25519_dalek::SigningKey> for ED25519SecretKey {
+    fn from(secret_key: &ed25519_dalek::SigningKey) -> Self {
+        Self(secret_key.to_keypair_bytes())
+    }
+}
+
+pub(crate) const PRIVTAE_KEY_DEFAULT_RSA_KEY_BITS: usize = 2048;
+
+const BASE58CHECK_CHECKSUM_LENGTH: usize = 4;
+
+fn base58check_checksum(payload: &[u8]) -> [u8; BASE58CHECK_CHECKSUM_LENGTH] {
+    use sha2::Digest;
+    let hash = sha2::Sha256::digest(sha2::Sha256::digest(payload));
+    let mut checksum = [0; BASE58CHECK_CHECKSUM_LENGTH];
+    checksum.copy_from_slice(&hash[..BASE58CHECK_CHECKSUM_LENGTH]);
+    checksum
+}
+
+/// Encodes `payload` in base58check, i.e. base58 of the payload followed by
+/// the first four bytes of its double SHA-256.  The intermediate buffer is
+/// wiped since the payload may be a secret key.
+fn base58check_encode(payload: &[u8]) -> String {
+    let mut data = Zeroizing::new(Vec::with_capacity(
+        payload.len().saturating_add(BASE58CHECK_CHECKSUM_LENGTH),
+    ));
+    data.extend_from_slice(payload);
+    data.extend_from_slice(&base58check_checksum(payload));
+    bs58::encode(data.as_slice()).into_string()
+}
+
+/// Decodes base58check-encoded data verifying and stripping the checksum.
+/// The data is wiped once dropped since it may be a secret key.
+fn base58check_decode(encoded: &str) -> Result<Zeroizing<Vec<u8>>, DecodeBs58Error> {
+    let mut data = Zeroizing::new(
+        bs58::decode(encoded)
+            .into_vec()
+            .map_err(|err| DecodeBs58Error::BadData(err.to_string()))?,
+    );
+    let Some(payload_len) = data.len().checked_sub(BASE58CHECK_CHECKSUM_LENGTH) else {
+        return Err(DecodeBs58Error::BadData("missing checksum".to_string()));
+    };
+    if data[payload_len..] != base58check_checksum(&data[..payload_len]) {
+        return Err(DecodeBs58Error::ChecksumMismatch);
+    }
+    data.truncate(payload_len);
+    Ok(data)
+}
+
+/// Converts the `keytype:base58` string form of a key or signature into
+/// `keytype:base58check`, i.e. appends a checksum to the data.
+fn bs58_to_base58check(value: &str) -> String {
+    let (prefix, data) = value.split_once(':').expect("string form has key type prefix");
+    let data = Zeroizing::new(bs58::decode(data).into_vec().expect("string form is valid base58"));
+    format!("{prefix}:{}", base58check_encode(&data))
+}
+
+/// Inverse of [`bs58_to_base58check`]: verifies and strips the checksum so
+/// that the result can be parsed by `FromStr` with all of its validation.
+/// Input without the key type prefix is returned without one.
+fn base58check_to_bs58(value: &str) -> Result<Zeroizing<String>, DecodeBs58Error> {
+    let (prefix, data) = match value.split_once(':') {
+        Some((prefix, data)) => (Some(prefix), data),
+        None => (None, value),
+    };
+    let payload = base58check_decode(data.trim())?;
+    Ok(Zeroizing::new(match prefix {
+        Some(prefix) => format!("{prefix}:{}", Bs58(&payload)),
+        None => Bs58(&payload).to_string(),
+    }))
+}
+
+/// Secret key container supporting different curves.
+///
+/// Same as with [`PublicKey`], a bare base58 string without the key type
+/// prefix is parsed (and deserialized) as an ED25519 key.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum SecretKey {
+    ED25519(ED25519SecretKey),
+    SECP256K1(secp256k1::SecretKey),
+    RSA(Box<rsa::RsaPrivateKey>),
+    P256(p256::ecdsa::SigningKey),
+}
+
+impl SecretKey {
+    pub fn key_type(&self) -> KeyType {
+        match self {
+            SecretKey::ED25519(_) => KeyType::ED25519,
+            SecretKey::SECP256K1(_) => KeyType::SECP256K1,
+            SecretKey::RSA(_) => KeyType::RSA2048,
+            SecretKey::P256(_) => KeyType::P256,
+        }
+    }
+
+    pub fn from_random(key_type: KeyType) -> SecretKey {
+        match key_type {
+            KeyType::ED25519 => {
+                let keypair = ed25519_dalek::SigningKey::generate(&mut OsRng);
+                SecretKey::ED25519(ED25519SecretKey(keypair.to_keypair_bytes()))
+            }
+            KeyType::SECP256K1 => SecretKey::SECP256K1(secp256k1::SecretKey::new(&mut OsRng)),
+            KeyType::RSA2048 => SecretKey::RSA(Box::new(
+                rsa::RsaPrivateKey::new(&mut OsRng, PRIVTAE_KEY_DEFAULT_RSA_KEY_BITS).unwrap(),
+            )),
+            KeyType::P256 => SecretKey::P256(p256::ecdsa::SigningKey::random(&mut OsRng)),
+        }
+    }
+
+    /// Generates `n` random key pairs of the given type.
+    ///
+    /// RSA key generation is slow so RSA keys are generated in parallel on
+    /// the rayon thread pool.  Use [`SecretKey::from_random_batch_sequential`]
+    /// to generate them on the calling thread instead.
+    pub fn from_random_batch(key_type: KeyType, n: usize) -> Vec<(SecretKey, PublicKey)> {
+        match key_type {
+            KeyType::RSA2048 => {
+                (0..n).into_par_iter().map(|_| Self::random_key_pair(key_type)).collect()
+            }
+            _ => Self::from_random_batch_sequential(key_type, n),
+        }
+    }
+
+    /// Same as [`SecretKey::from_random_batch`] but generates all the keys on
+    /// the calling thread.
+    pub fn from_random_batch_sequential(
+        key_type: KeyType,
+        n: usize,
+    ) -> Vec<(SecretKey, PublicKey)> {
+        (0..n).map(|_| Self::random_key_pair(key_type)).collect()
+    }
+
+    fn random_key_pair(key_type: KeyType) -> (SecretKey, PublicKey) {
+        let secret_key = Self::from_random(key_type);
+        let public_key = secret_key.public_key();
+        (secret_key, public_key)
+    }
+
+    /// Signs `data` with the secret key.
+    ///
+    /// What `data` is depends on the key type:
+    ///
+    /// - Ed25519 and P-256 sign the raw message of arbitrary length (P-256
+    ///   hashes it with SHA-256 first);
+    /// - secp256k1 signs `data` as is, so it must be a 32-byte digest which
+    ///   the caller has already computed;
+    /// - RSA uses unprefixed PKCS#1 v1.5 padding, i.e. also signs `data` as
+    ///   is, so it should be a digest too.
+    ///
+    /// Thus digest-based key types must not be given a message which gets
+    /// hashed again on the verifying side, or the other way around.
+    ///
+    /// ```
+    /// use sha2::Digest;
+    /// use unc_crypto::{KeyType, SecretKey};
+    ///
+    /// let message = b"message";
+    /// for key_type in [KeyType::ED25519, KeyType::P256] {
+    ///     let secret_key = SecretKey::from_seed(key_type, "test");
+    ///     let signature = secret_key.sign(message);
+    ///     assert!(signature.verify(message, &secret_key.public_key()));
+    /// }
+    /// let digest: [u8; 32] = sha2::Sha256::digest(message).into();
+    /// for key_type in [KeyType::SECP256K1, KeyType::RSA2048] {
+    ///     let secret_key = SecretKey::from_seed(key_type, "test");
+    ///     let signature = secret_key.sign(&digest);
+    ///     assert!(signature.verify(&digest, &secret_key.public_key()));
+    /// }
+    /// ```
+    ///
+    /// Panics if `data` isn't a 32-byte digest for a secp256k1 key, use
+    /// [`SecretKey::try_sign`] to get an error instead, or
+    /// [`SecretKey::sign_secp256k1_digest`] which makes the digest explicit.
+    pub fn sign(&self, data: &[u8]) -> Signature {
+        self.try_sign(data).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Same as [`SecretKey::sign`] but takes anything which can be viewed as
+    /// bytes, e.g. `Vec<u8>` or an array, sparing the `&data[..]` at call
+    /// sites.  `sign` itself keeps taking `&[u8]` so that `x.as_ref()`
+    /// arguments still infer their type.
+    pub fn sign_ref(&self, data: impl AsRef<[u8]>) -> Signature {
+        self.sign(data.as_ref())
+    }
+
+    /// Same as [`SecretKey::sign`] but returns
+    /// [`SigningError::MessageLength`](crate::errors::SigningError::MessageLength)
+    /// rather than panicking if `data` isn't a 32-byte digest for a secp256k1
+    /// key.
+    pub fn try_sign(&self, data: &[u8]) -> Result<Signature, crate::errors::SigningError> {
+        Ok(match &self {
+            SecretKey::ED25519(secret_key) => {
+                let keypair = ed25519_dalek::SigningKey::from_keypair_bytes(&secret_key.0).unwrap();
+                Signature::ED25519(keypair.sign(data))
+            }
+
+            SecretKey::SECP256K1(secret_key) => {
+                let digest = Digest32::try_from(data).map_err(|_| {
+                    crate::errors::SigningError::MessageLength {
+                        expected: secp256k1::constants::MESSAGE_SIZE,
+                        received: data.len(),
+                    }
+                })?;
+                sign_secp256k1(secret_key, digest)
+            }
+            SecretKey::RSA(secret_key) => {
+                let sign_data = secret_key.sign(Pkcs1v15Sign::new_unprefixed(), data).unwrap();
+                Signature::RSA(Rsa2048Signature(
+                    <[u8; 256]>::try_from(sign_data.as_slice()).unwrap(),
+                ))
+            }
+            SecretKey::P256(secret_key) => {
+                p256_signature(p256::ecdsa::signature::Signer::sign(secret_key, data))
+            }
+        })
+    }
+
+    /// Signs an already computed 32-byte digest.
+    ///
+    /// Unlike [`SecretKey::sign`] this never fails for secp256k1 keys since
+    /// the digest length is guaranteed by the type.  P-256 uses `digest` as the
+    /// final hash rather than hashing it again, and other key types sign the
+    /// digest as the message.  This mirrors [`Signature::verify_digest`].
+    pub fn sign_digest(&self, digest: &Digest32) -> Signature {
+        match self {
+            SecretKey::SECP256K1(secret_key) => sign_secp256k1(secret_key, *digest),
+            SecretKey::P256(secret_key) => p256_signature(
+                p256::ecdsa::signature::hazmat::PrehashSigner::sign_prehash(
+                    secret_key,
+                    digest.as_bytes(),
+                )
+                .expect("32-byte prehash is always accepted"),
+            ),
+            SecretKey::ED25519(_) | SecretKey::RSA(_) => self.sign(digest.as_bytes()),
+        }
+    }
+
+    /// Signs `data` bound to the domain `context` so that the signature can't
+    /// be reused in another protocol.  What's signed is the 32-byte digest
+    /// `sha256(len(context) || context || data)`, where `len(context)` is the
+    /// context length encoded as a little-endian u32, for all key types.
+    ///
+    /// Use [`Signature::verify_with_context`] to verify the signature.  Fails
+    /// if the context is longer than `u32::MAX` bytes.
+    pub fn sign_with_context(
+        &self,
+        context: &[u8],
+        data: &[u8],
+    ) -> Result<Signature, crate::errors::SigningError> {
+        Ok(self.sign(&context_digest(context, data)?))
+    }
+
+    /// Same as [`PublicKey::to_base58check`] but for secret keys.
+    pub fn to_base58check(&self) -> String {
+        bs58_to_base58check(&Zeroizing::new(self.to_string()))
+    }
+
+    /// Same as [`PublicKey::from_base58check`] but for secret keys.
+    pub fn from_base58check(value: &str) -> Result<Self, crate::errors::ParseKeyError> {
+        Self::from_str(&base58check_to_bs58(value)?)
+    }
+
+    /// Parses secret key from its string form and derives its public key,
+    /// e.g. when loading a key from a config file.
+    pub fn parse_with_public_key(
+        value: &str,
+    ) -> Result<(SecretKey, PublicKey), crate::errors::ParseKeyError> {
+        let secret_key = Self::from_str(value)?;
+        let public_key = secret_key.public_key();
+        Ok((secret_key, public_key))
+    }
+
+    /// Signs `data` and returns the signature in its `keytype:base58` string
+    /// form, same as `self.sign(data).to_string()`.
+    pub fn sign_to_string(&self, data: &[u8]) -> String {
+        self.sign(data).to_string()
+    }
+
+    pub fn public_key(&self) -> PublicKey {
+        match &self {
+            SecretKey::ED25519(secret_key) => PublicKey::ED25519(ED25519PublicKey(
+                secret_key.0[ed25519_dalek::SECRET_KEY_LENGTH..].try_into().unwrap(),
+            )),
+            SecretKey::SECP256K1(secret_key) => {
+                let pk = secp256k1::PublicKey::from_secret_key(&SECP256K1, secret_key);
+                let serialized = pk.serialize_uncompressed();
+                let mut public_key = Secp256K1PublicKey([0; 64]);
+                public_key.0.copy_from_slice(&serialized[1..65]);
+                PublicKey::SECP256K1(public_key)
+            }
+            SecretKey::RSA(secret_key) => {
+                let pk = secret_key.to_public_key();
+                let mut public_key = [0; RAW_PUBLIC_KEY_RSA_2048_LENGTH];
+                public_key.copy_from_slice(&pk.to_public_key_der().unwrap().as_bytes());
+                PublicKey::RSA(Box::new(Rsa2048PublicKey(public_key)))
+            }
+            SecretKey::P256(secret_key) => {
+                let serialized = secret_key.verifying_key().to_encoded_point(false);
+                let mut public_key = P256PublicKey([0; PUBLIC_KEY_P256_LENGTH]);
+                public_key.0.copy_from_slice(&serialized.as_bytes()[1..65]);
+                PublicKey::P256(public_key)
+            }
+        }
+    }
+
+    /// Returns regular expression matching the `keytype:base58` string form the
+    /// key is serialized as.  See [`PublicKey::schema_pattern`].
+    pub fn schema_pattern() -> &'static str {
+        KEY_SCHEMA_PATTERN
+    }
+
+    /// Same as [`SecretKey::from_str`] but requires an explicit `keytype:`
+    /// prefix rather than defaulting to ED25519 when it's missing.
+    pub fn from_str_strict(value: &str) -> Result<Self, crate::errors::ParseKeyError> {
+        if !has_key_type_prefix(value) {
+            return Err(crate::errors::ParseKeyError::MissingKeyTypePrefix);
+        }
+        Self::from_str(value)
+    }
+
+    /// Checks whether `public_key` corresponds to this secret key by deriving
+    /// the public key from it and comparing the two.  For RSA this compares
+    /// the DER encodings of the public keys.
+    pub fn matches_public_key(&self, public_key: &PublicKey) -> bool {
+        self.public_key() == *public_key
+    }
+
+    pub fn unwrap_as_ed25519(&self) -> &ED25519SecretKey {
+        match self {
+            SecretKey::ED25519(key) => key,
+            _ => panic!(),
+        }
+    }
+
+    pub fn unwrap_as_secp256k1(&self) -> &secp256k1::SecretKey {
+        match self {
+            SecretKey::SECP256K1(key) => key,
+            _ => panic!(),
+        }
+    }
+
+    pub fn unwrap_as_rsa2048(&self) -> &rsa::RsaPrivateKey {
+        match self {
+            SecretKey::RSA(key) => key,
+            _ => panic!(),
+        }
+    }
+
+    pub fn unwrap_as_p256(&self) -> &p256::ecdsa::SigningKey {
+        match self {
+            SecretKey::P256(key) => key,
+            _ => panic!(),
+        }
+    }
+
+    /// Fallible counterpart of [`SecretKey::unwrap_as_ed25519`].
+    pub fn try_as_ed25519(&self) -> Result<&ED25519SecretKey, crate::errors::KeyConversionError> {
+        match self {
+            SecretKey::ED25519(key) => Ok(key),
+            _ => Err(key_type_mismatch(KeyType::ED25519, self.key_type())),
+        }
+    }
+
+    /// Fallible counterpart of [`SecretKey::unwrap_as_secp256k1`].
+    pub fn try_as_secp256k1(
+        &self,
+    ) -> Result<&secp256k1::SecretKey, crate::errors::KeyConversionError> {
+        match self {
+            SecretKey::SECP256K1(key) => Ok(key),
+            _ => Err(key_type_mismatch(KeyType::SECP256K1, self.key_type())),
+        }
+    }
+
+    /// Fallible counterpart of [`SecretKey::unwrap_as_rsa2048`].
+    pub fn try_as_rsa2048(&self) -> Result<&rsa::RsaPrivateKey, crate::errors::KeyConversionError> {
+        match self {
+            SecretKey::RSA(key) => Ok(key),
+            _ => Err(key_type_mismatch(KeyType::RSA2048, self.key_type())),
+        }
+    }
+
+    /// Fallible counterpart of [`SecretKey::unwrap_as_p256`].
+    pub fn try_as_p256(
+        &self,
+    ) -> Result<&p256::ecdsa::SigningKey, crate::errors::KeyConversionError> {
+        match self {
+            SecretKey::P256(key) => Ok(key),
+            _ => Err(key_type_mismatch(KeyType::P256, self.key_type())),
+        }
+    }
+
+    /// Returns raw key data, i.e. the same bytes as in the string form but
+    /// without the key type prefix.  See [`SecretKey::from_parts`] for the
+    /// encoding of each key type.  The bytes are wiped once dropped.
+    pub fn to_secret_bytes(&self) -> Zeroizing<Vec<u8>> {
+        Zeroizing::new(match self {
+            SecretKey::ED25519(secret_key) => secret_key.0.to_vec(),
+            SecretKey::SECP256K1(secret_key) => secret_key.secret_bytes().to_vec(),
+            SecretKey::RSA(secret_key) => secret_key.to_pkcs8_der().unwrap().as_bytes().to_vec(),
+            SecretKey::P256(secret_key) => secret_key.to_bytes().to_vec(),
+        })
+    }
+
+    /// Constructs secret key from key type and raw key data, e.g. when the two
+    /// are stored separately.  The data is:
+    ///
+    /// - for Ed25519 the 64-byte keypair, i.e. seed followed by the public
+    ///   key, or just the 32-byte seed;
+    /// - for secp256k1 and P-256 the 32-byte scalar;
+    /// - for RSA the PKCS#8 DER encoding.
+    pub fn from_parts(
+        key_type: KeyType,
+        key_data: &[u8],
+    ) -> Result<Self, crate::errors::ParseKeyError> {
+        use crate::errors::ParseKeyError;
+        let invalid_length = |expected_length| ParseKeyError::InvalidLength {
+            expected_length,
+            received_length: key_data.len(),
+        };
+        let invalid_data =
+            |err: &dyn Display| ParseKeyError::InvalidData { error_message: err.to_string() };
+        Ok(match key_type {
+            KeyType::ED25519 => {
+                if let Ok(seed) = <&[u8; ed25519_dalek::SECRET_KEY_LENGTH]>::try_from(key_data) {
+                    return Ok(Self::ED25519(ED25519SecretKey::from_seed_bytes(seed)));
+                }
+                let keypair = Zeroizing::new(
+                    <[u8; ed25519_dalek::KEYPAIR_LENGTH]>::try_from(key_data)
+                        .map_err(|_| invalid_length(ed25519_dalek::KEYPAIR_LENGTH))?,
+                );
+                // Make sure the embedded public key matches the secret so that
+                // signing with the key can't fail later.
+                ed25519_dalek::SigningKey::from_keypair_bytes(&keypair)
+                    .map_err(|err| invalid_data(&err))?;
+                Self::ED25519(ED25519SecretKey(*keypair))
+            }
+            KeyType::SECP256K1 => {
+                if key_data.len() != secp256k1::constants::SECRET_KEY_SIZE {
+                    return Err(invalid_length(secp256k1::constants::SECRET_KEY_SIZE));
+                }
+                Self::SECP256K1(
+                    secp256k1::SecretKey::from_slice(key_data).map_err(|err| invalid_data(&err))?,
+                )
+            }
+            KeyType::RSA2048 => Self::RSA(Box::new(
+                rsa::RsaPrivateKey::from_pkcs8_der(key_data).map_err(|err| invalid_data(&err))?,
+            )),
+            KeyType::P256 => {
+                if key_data.len() != P256_SECRET_KEY_LENGTH {
+                    return Err(invalid_length(P256_SECRET_KEY_LENGTH));
+                }
+                Self::P256(
+                    p256::ecdsa::SigningKey::from_slice(key_data)
+                        .map_err(|err| invalid_data(&err))?,
+                )
+            }
+        })
+    }
+}
+
+// This `Hash` implementation is safe since it retains the property
+// `k1 == k2 ⇒ hash(k1) == hash(k2)`: ED25519 keys hash only the secret half,
+// as compared by `PartialEq`, and RSA keys hash only the public modulus which
+// equal keys share.
+//
+// Hashing isn't constant-time and a hasher seeing the secret bytes may leak
+// them through timing.  Where that matters key the collection by
+// `SecretKey::public_key` instead.
+impl Hash for SecretKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u8(self.key_type() as u8);
+        match self {
+            SecretKey::ED25519(secret_key) => {
+                state.write(&secret_key.0[..ed25519_dalek::SECRET_KEY_LENGTH])
+            }
+            SecretKey::SECP256K1(secret_key) => state.write(&secret_key.secret_bytes()),
+            SecretKey::RSA(secret_key) => {
+                use rsa::traits::PublicKeyParts;
+                state.write(&secret_key.n().to_bytes_be())
+            }
+            SecretKey::P256(secret_key) => state.write(&secret_key.to_bytes()),
+        }
+    }
+}
+
+/// Derives the public key from raw secret key bytes of the given key type.
+///
+/// The bytes are expected in the following form:
+/// - ED25519: either the 32-byte secret or the 64-byte keypair (in which case
+///   the embedded public half must match the secret),
+/// - SECP256K1 and P256: the 32-byte scalar,
+/// - RSA2048: PKCS#8 DER encoding, same as in the string form of the key.
+///
+/// The result is the same as `SecretKey::public_key` of the corresponding
+/// secret key.
+pub fn public_key_from_secret_bytes(
+    key_type: KeyType,
+    bytes: &[u8],
+) -> Result<PublicKey, crate::errors::ParseKeyError> {
+    SecretKey::from_parts(key_type, bytes).map(|secret_key| secret_key.public_key())
+}
+
+impl std::fmt::Display for SecretKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            SecretKey::ED25519(secret_key) => {
+                write!(f, "{}:{}", KeyType::ED25519, Bs58(&secret_key.0[..]))
+            }
+            SecretKey::SECP256K1(secret_key) => {
+                write!(f, "{}:{}", KeyType::SECP256K1, Bs58(&secret_key[..]))
+            }
+            SecretKey::RSA(secret_key) => {
+                // 先将 DER 编码的密钥存储在一个变量中
+                let pkcs8_bytes = secret_key.to_pkcs8_der().unwrap().to_bytes();
+                // 然后获取它的切片
+                write!(f, "{}:{}", KeyType::RSA2048, Bs58(&pkcs8_bytes.as_slice()))
+            }
+            SecretKey::P256(secret_key) => {
+                write!(f, "{}:{}", KeyType::P256, Bs58(&secret_key.to_bytes()))
+            }
+        }
+    }
+}
+
+impl FromStr for SecretKey {
+    type Err = crate::errors::ParseKeyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (key_type, key_data) = split_key_type_data(s)?;
+        let max_len = match key_type {
+            KeyType::ED25519 => ed25519_dalek::KEYPAIR_LENGTH,
+            KeyType::SECP256K1 => secp256k1::constants::SECRET_KEY_SIZE,
+            KeyType::RSA2048 => 2048,
+            KeyType::P256 => P256_SECRET_KEY_LENGTH,
+        };
+        let data = parse_bs58_data(max_len, key_data)?;
+        // Unlike the raw data accepted by `from_parts`, the string form always
+        // holds the whole Ed25519 keypair.
+        if matches!(key_type, KeyType::ED25519) && data.len() != max_len {
+            return Err(Self::Err::InvalidLength {
+                expected_length: max_len,
+                received_length: data.len(),
+            });
+        }
+        Self::from_parts(key_type, &data)
+    }
+}
+
+impl serde::Serialize for SecretKey {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> Result<<S as serde::Serializer>::Ok, <S as serde::Serializer>::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for SecretKey {
+    fn deserialize<D>(deserializer: D) -> Result<Self, <D as serde::Deserializer<'de>>::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(|err| serde::de::Error::custom(err.to_string()))
+    }
+}
+
+/// Constructs ED25519 signature from its bytes applying the same sanity-check
+/// as Borsh deserialization so that both paths accept the same signatures.
+fn ed25519_signature_from_bytes(
+    bytes: &[u8; ed25519_dalek::SIGNATURE_LENGTH],
+) -> Option<ed25519_dalek::Signature> {
+    // Sanity-check that was performed by ed25519-dalek in from_bytes before version 2,
+    // but was removed with version 2. It is not actually any good a check, but we have
+    // it here in case we need to keep backward compatibility. Maybe this check is not
+    // actually required, but please think carefully before removing it.
+    if bytes[ed25519_dalek::SIGNATURE_LENGTH - 1] & 0b1110_0000 != 0 {
+        return None;
+    }
+    Some(ed25519_dalek::Signature::from_bytes(bytes))
+}
+
+/// Signature container supporting different curves.
+///
+/// Same as with [`PublicKey`], a bare base58 string without the key type
+/// prefix is parsed (and deserialized) as an ED25519 signature.  Use
+/// [`Signature::from_str_strict`] to require the prefix.
+#[derive(Clone, PartialEq, Eq)]
+pub enum Signature {
+    ED25519(ed25519_dalek::Signature),
+    SECP256K1(Secp256K1Signature),
+    RSA(Rsa2048Signature),
+    P256(P256Signature),
+}
+
+// This `Hash` implementation is safe since it retains the property
+// `k1 == k2 ⇒ hash(k1) == hash(k2)`.
+impl Hash for Signature {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Signature::ED25519(sig) => sig.to_bytes().hash(state),
+            Signature::SECP256K1(sig) => sig.hash(state),
+            Signature::RSA(sig) => sig.hash(state),
+            Signature::P256(sig) => sig.hash(state),
+        };
+    }
+}
+
+impl Signature {
+    /// Returns length of the borsh encoding of the signature, i.e. the tag
+    /// byte followed by the signature data, without serializing it.
+    pub fn borsh_len(&self) -> usize {
+        const ED25519_LEN: usize = ed25519_dalek::SIGNATURE_LENGTH + 1;
+        match self {
+            Signature::ED25519(_) => ED25519_LEN,
+            Signature::SECP256K1(_) => SECP256K1_SIGNATURE_LENGTH + 1,
+            Signature::RSA(_) => RSA2048_SIGNATURE_LENGTH + 1,
+            Signature::P256(_) => P256_SIGNATURE_LENGTH + 1,
+        }
+    }
+
+    /// All-zero placeholder signature of the given key type, e.g. for a
+    /// transaction which isn't signed yet.  Mirrors [`PublicKey::empty`].
+    ///
+    /// Empty signatures never verify against any key.
+    pub fn empty(key_type: KeyType) -> Self {
+        match key_type {
+            KeyType::ED25519 => Signature::ED25519(ed25519_dalek::Signature::from_bytes(
+                &[0; ed25519_dalek::SIGNATURE_LENGTH],
+            )),
+            KeyType::SECP256K1 => {
+                Signature::SECP256K1(Secp256K1Signature([0; SECP256K1_SIGNATURE_LENGTH]))
+            }
+            KeyType::RSA2048 => Signature::RSA(Rsa2048Signature([0; RSA2048_SIGNATURE_LENGTH])),
+            KeyType::P256 => Signature::P256(P256Signature([0; P256_SIGNATURE_LENGTH])),
+        }
+    }
+
+    /// Same as [`PublicKey::to_base58check`] but for signatures.
+    pub fn to_base58check(&self) -> String {
+        bs58_to_base58check(&self.to_string())
+    }
+
+    /// Same as [`PublicKey::from_base58check`] but for signatures.
+    pub fn from_base58check(value: &str) -> Result<Self, crate::errors::ParseSignatureError> {
+        Self::from_str(&base58check_to_bs58(value)?)
+    }
+
+    /// Construct Signature from key type and raw signature blob
+    pub fn from_parts(
+        signature_type: KeyType,
+        signature_data: &[u8],
+    ) -> Result<Self, crate::errors::ParseSignatureError> {
+        match signature_type {
+            KeyType::ED25519 => {
+                let array = <&[u8; ed25519_dalek::SIGNATURE_LENGTH]>::try_from(signature_data)
+                    .map_err(|err| crate::errors::ParseSignatureError::InvalidData {
+                        error_message: err.to_string(),
+                    })?;
+                Ok(Signature::ED25519(ed25519_signature_from_bytes(array).ok_or_else(|| {
+                    crate::errors::ParseSignatureError::InvalidData {
+                        error_message: "invalid ED25519 signature".to_string(),
+                    }
+                })?))
+            }
+            KeyType::SECP256K1 => {
+                Ok(Signature::SECP256K1(Secp256K1Signature::try_from(signature_data).map_err(
+                    |_| crate::errors::ParseSignatureError::InvalidData {
+                        error_message: "invalid Secp256k1 signature length".to_string(),
+                    },
+                )?))
+            }
+            KeyType::RSA2048 => {
+                Ok(Signature::RSA(Rsa2048Signature::try_from(signature_data).map_err(|_| {
+                    crate::errors::ParseSignatureError::InvalidData {
+                        error_message: "invalid RSA2048 signature length".to_string(),
+                    }
+                })?))
+            }
+            KeyType::P256 => {
+                Ok(Signature::P256(P256Signature::try_from(signature_data).map_err(|_| {
+                    crate::errors::ParseSignatureError::InvalidData {
+                        error_message: "invalid P256 signature length".to_string(),
+                    }
+                })?))
+            }
+        }
+    }
+
+    /// Constructs signature from the raw signature data prefixed with the
+    /// signature type byte, i.e. its Borsh serialization.  Fails if the data
+    /// is truncated or has trailing bytes.
+    pub fn try_from_tagged(data: &[u8]) -> Result<Self, crate::errors::ParseSignatureError> {
+        use crate::errors::ParseSignatureError;
+        let (&tag, signature_data) = data
+            .split_first()
+            .ok_or(ParseSignatureError::InvalidLength { expected_length: 1, received_length: 0 })?;
+        let signature_type = KeyType::try_from(tag)?;
+        if let Some(expected_length) = signature_type.signature_len() {
+            if signature_data.len() != expected_length {
+                return Err(ParseSignatureError::InvalidLength {
+                    expected_length,
+                    received_length: signature_data.len(),
+                });
+            }
+        }
+        Self::from_parts(signature_type, signature_data)
+    }
+
+    /// Encodes the signature in the compact binary wire format: a single tag
+    /// byte followed by the fixed-length signature data.
+    ///
+    /// The format is stable and is identical to the Borsh serialization:
+    ///
+    /// | tag    | signature                 | data                                   |
+    /// |--------|---------------------------|----------------------------------------|
+    /// | `0x00` | ED25519                   | 64 bytes, `R ‖ S`                      |
+    /// | `0x01` | SECP256K1                 | 65 bytes, `r ‖ s ‖ v` (recovery id)    |
+    /// | `0x02` | RSA2048, PKCS#1 v1.5      | 256 bytes                              |
+    /// | `0x03` | P256                      | 64 bytes, `r ‖ s`                      |
+    ///
+    /// Use [`Signature::decode_compact`] to decode it.
+    pub fn encode_compact(&self) -> Vec<u8> {
+        let ed25519;
+        let (tag, data): (u8, &[u8]) = match self {
+            Signature::ED25519(signature) => {
+                ed25519 = signature.to_bytes();
+                (KeyType::ED25519 as u8, &ed25519[..])
+            }
+            Signature::SECP256K1(signature) => (KeyType::SECP256K1 as u8, &signature.0[..]),
+            Signature::RSA(signature) => (KeyType::RSA2048 as u8, &signature.0[..]),
+            Signature::P256(signature) => (KeyType::P256 as u8, &signature.0[..]),
+        };
+        let mut encoded = Vec::with_capacity(data.len().saturating_add(1));
+        encoded.push(tag);
+        encoded.extend_from_slice(data);
+        encoded
+    }
+
+    /// Decodes signature encoded with [`Signature::encode_compact`].
+    pub fn decode_compact(data: &[u8]) -> Result<Self, crate::errors::ParseSignatureError> {
+        Self::try_from_tagged(data)
+    }
+
+    /// Returns regular expression matching the `keytype:base58` string form the
+    /// signature is serialized as.  See [`PublicKey::schema_pattern`].
+    pub fn schema_pattern() -> &'static str {
+        KEY_SCHEMA_PATTERN
+    }
+
+    /// Same as [`Signature::from_str`] but requires an explicit `keytype:`
+    /// prefix rather than defaulting to ED25519 when it's missing.
+    pub fn from_str_strict(value: &str) -> Result<Self, crate::errors::ParseSignatureError> {
+        if !has_key_type_prefix(value) {
+            return Err(crate::errors::ParseSignatureError::MissingKeyTypePrefix);
+        }
+        Self::from_str(value)
+    }
+
+    /// Verifies that this signature is indeed signs the data with given public key.
+    /// Also if public key doesn't match on the curve returns `false`.
+    ///
+    /// Note that secp256k1 signatures are made over a 32-byte digest, so for
+    /// them `data` of any other length makes this return `false`.  Use
+    /// [`Signature::verify_checked`] to tell that case apart.
+    ///
+    /// The last byte of a secp256k1 signature is the recovery id which must be
+    /// in `0..=3`; any other value makes this return `false`.  Within that range
+    /// the recovery id isn't checked against the key, it only matters for
+    /// [`Secp256K1Signature::recover`].
+    pub fn verify(&self, data: &[u8], public_key: &PublicKey) -> bool {
+        self.verify_detailed(data, public_key).is_ok()
+    }
+
+    /// Same as [`Signature::verify`] but ED25519 signatures are verified with
+    /// the stricter `verify_strict` of `ed25519_dalek`.
+    ///
+    /// Lenient verification accepts some signatures no honest signer produces:
+    /// ones made with a small-order public key, or whose `R` is a small-order
+    /// point, which makes it possible to forge a signature valid for several
+    /// messages or to craft alternative (malleable) encodings.  Strict
+    /// verification rejects those so every node agrees on the outcome, which
+    /// is what consensus-critical code wants.  Signatures of honest signers
+    /// verify the same either way.  Other key types are verified as usual.
+    pub fn verify_strict(&self, data: &[u8], public_key: &PublicKey) -> bool {
+        match (self, public_key) {
+            (Signature::ED25519(signature), PublicKey::ED25519(public_key)) => {
+                match ed25519_dalek::VerifyingKey::from_bytes(&public_key.0) {
+                    Ok(public_key) => public_key.verify_strict(data, signature).is_ok(),
+                    Err(_) => false,
+                }
+            }
+            _ => self.verify(data, public_key),
+        }
+    }
+
+    /// Same as [`Signature::verify`] but takes anything which can be viewed as
+    /// bytes, see [`SecretKey::sign_ref`].
+    pub fn verify_ref(&self, data: impl AsRef<[u8]>, public_key: &PublicKey) -> bool {
+        self.verify(data.as_ref(), public_key)
+    }
+
+    /// Verifies a signature made by [`SecretKey::sign_with_context`] with the
+    /// same `context`.  Returns `false` if the context is too long to have
+    /// been signed.
+    pub fn verify_with_context(&self, context: &[u8], data: &[u8], public_key: &PublicKey) -> bool {
+        context_digest(context, data).map_or(false, |digest| self.verify(&digest, public_key))
+    }
+
+    /// Runs cheap structural checks of the signature which don't need the
+    /// public key, allowing to reject garbage early:
+    /// - secp256k1: the recovery id is in range and `r`, `s` are in range with
+    ///   `s` in the lower half (see [`Secp256K1Signature::check_signature_values`]);
+    /// - P-256: `r` and `s` are non-zero and less than the curve order with `s`
+    ///   in the lower half, same as verification requires;
+    /// - Ed25519: the high bits of the last byte aren't set (same check as in
+    ///   Borsh deserialization).
+    ///
+    /// Passing the checks doesn't mean the signature is valid.
+    pub fn validate_structure(&self) -> Result<(), crate::errors::ValidationError> {
+        use crate::errors::ValidationError;
+        match self {
+            Signature::ED25519(signature) => {
+                ed25519_signature_from_bytes(&signature.to_bytes())
+                    .ok_or(ValidationError::Ed25519HighBits)?;
+            }
+            Signature::SECP256K1(signature) => {
+                let recovery_id = signature.0[64];
+                if recovery_id > 3 {
+                    return Err(ValidationError::InvalidRecoveryId { recovery_id });
+                }
+                if !signature.check_signature_values(true) {
+                    return Err(ValidationError::InvalidSignatureValues);
+                }
+            }
+            Signature::P256(signature) => {
+                parse_p256_signature(&signature.0)
+                    .ok_or(ValidationError::InvalidSignatureValues)?;
+            }
+            Signature::RSA(_) => {}
+        }
+        Ok(())
+    }
+
+    /// Verifies the signature over an already computed 32-byte digest.
+    ///
+    /// For secp256k1 and RSA this is the same as [`Signature::verify`] since
+    /// those sign a digest anyway, and Ed25519 treats the digest as the message
+    /// it signs.  The difference is P-256: `verify` hashes `data` with SHA-256
+    /// before verifying whereas this method uses `digest` as the final hash.
+    /// Thus for P-256 `verify_digest(&sha256(data), pk)` is equivalent to
+    /// `verify(data, pk)`.
+    ///
+    /// `digest` may be given either as [`Digest32`] or as a 32-byte array.
+    pub fn verify_digest(&self, digest: impl Into<Digest32>, public_key: &PublicKey) -> bool {
+        let digest = digest.into();
+        match (self, public_key) {
+            (Signature::P256(signature), PublicKey::P256(public_key)) => {
+                let (Ok(pub_key), Some(sig)) =
+                    (public_key.verifying_key(), parse_p256_signature(&signature.0))
+                else {
+                    return false;
+                };
+                p256::ecdsa::signature::hazmat::PrehashVerifier::verify_prehash(
+                    &pub_key,
+                    digest.as_bytes(),
+                    &sig,
+                )
+                .is_ok()
+            }
+            _ => self.verify(digest.as_bytes(), public_key),
+        }
+    }
+
+    /// Verifies the signature against each of `public_keys` in order and returns
+    /// the index of the first key it verifies with, or `None` if there's none.
+    pub fn verify_any(&self, data: &[u8], public_keys: &[PublicKey]) -> Option<usize> {
+        public_keys.iter().position(|public_key| self.verify(data, public_key))
+    }
+
+    /// Same as [`Signature::verify_any`] but also returns the type of the
+    /// matching key, e.g. for logging or metrics.
+    pub fn verify_identify(
+        &self,
+        data: &[u8],
+        public_keys: &[PublicKey],
+    ) -> Option<(usize, KeyType)> {
+        self.verify_any(data, public_keys).map(|index| (index, public_keys[index].key_type()))
+    }
+
+    /// Same as [`Signature::verify`] but on failure reports why the
+    /// verification failed.
+    pub fn verify_detailed(
+        &self,
+        data: &[u8],
+        public_key: &PublicKey,
+    ) -> Result<(), crate::errors::VerifyFailure> {
+        use crate::errors::VerifyFailure;
+        match (&self, public_key) {
+            (Signature::ED25519(signature), PublicKey::ED25519(public_key)) => {
+                // The all-zero key is a valid (small order) curve point which
+                // no secret key maps to, make sure it's not reported as a mere
+                // signature mismatch.
+                if public_key.0 == [0; ed25519_dalek::PUBLIC_KEY_LENGTH] {
+                    return Err(VerifyFailure::InvalidPublicKey);
+                }
+                let public_key = ed25519_dalek::VerifyingKey::from_bytes(&public_key.0)
+                    .map_err(|_| VerifyFailure::InvalidPublicKey)?;
+                public_key.verify(data, signature).map_err(|_| VerifyFailure::SignatureMismatch)
+            }
+            (Signature::SECP256K1(signature), PublicKey::SECP256K1(public_key)) => {
+                // `from_i32` rejects recovery ids outside of 0..=3.
+                let rec_id = secp256k1::ecdsa::RecoveryId::from_i32(i32::from(signature.0[64]))
+                    .map_err(|_| VerifyFailure::MalformedSignature)?;
+                let rsig = secp256k1::ecdsa::RecoverableSignature::from_compact(
+                    &signature.0[0..64],
+                    rec_id,
+                )
+                .map_err(|_| VerifyFailure::MalformedSignature)?;
+                let sig = rsig.to_standard();
+                let pdata: [u8; 65] = {
+                    // code borrowed from https://github.com/openethereum/openethereum/blob/98b7c07171cd320f32877dfa5aa528f585dc9a72/ethkey/src/signature.rs#L210
+                    let mut temp = [4u8; 65];
+                    temp[1..65].copy_from_slice(&public_key.0);
+                    temp
+                };
+                let message = secp256k1::Message::from_slice(data)
+                    .map_err(|_| VerifyFailure::InvalidMessage)?;
+                let pub_key = secp256k1::PublicKey::from_slice(&pdata)
+                    .map_err(|_| VerifyFailure::InvalidPublicKey)?;
+                secp256k1_verifier()
+                    .verify_ecdsa(&message, &sig, &pub_key)
+                    .map_err(|_| VerifyFailure::SignatureMismatch)
+            }
+            (Signature::RSA(signature), PublicKey::RSA(public_key)) => {
+                let pk = rsa::RsaPublicKey::from_public_key_der(&public_key.0)
+                    .map_err(|_| VerifyFailure::InvalidPublicKey)?;
+                pk.verify(Pkcs1v15Sign::new_unprefixed(), &data, signature.0.as_ref())
+                    .map_err(|_| VerifyFailure::SignatureMismatch)
+            }
+            (Signature::P256(signature), PublicKey::P256(public_key)) => {
+                let pub_key =
+                    public_key.verifying_key().map_err(|_| VerifyFailure::InvalidPublicKey)?;
+                let sig =
+                    parse_p256_signature(&signature.0).ok_or(VerifyFailure::MalformedSignature)?;
+                p256::ecdsa::signature::Verifier::verify(&pub_key, data, &sig)
+                    .map_err(|_| VerifyFailure::SignatureMismatch)
+            }
+
+            _ => Err(VerifyFailure::KeyTypeMismatch {
+                signature_key_type: self.key_type(),
+                public_key_type: public_key.key_type(),
+            }),
+        }
+    }
+
+    /// Same as [`Signature::verify`] but returns an error rather than `false`
+    /// if `data` can't possibly be verified by this signature, i.e. when it
+    /// isn't a 32-byte digest for a secp256k1 signature.
+    pub fn verify_checked(
+        &self,
+        data: &[u8],
+        public_key: &PublicKey,
+    ) -> Result<bool, crate::errors::VerifyError> {
+        if let Signature::SECP256K1(_) = self {
+            if data.len() != secp256k1::constants::MESSAGE_SIZE {
+                return Err(crate::errors::VerifyError::InvalidMessageLength {
+                    expected_length: secp256k1::constants::MESSAGE_SIZE,
+                    received_length: data.len(),
+                });
+            }
+        }
+        Ok(self.verify(data, public_key))
+    }
+
+    pub fn key_type(&self) -> KeyType {
+        match self {
+            Signature::ED25519(_) => KeyType::ED25519,
+            Signature::SECP256K1(_) => KeyType::SECP256K1,
+            Signature::RSA(_) => KeyType::RSA2048,
+            Signature::P256(_) => KeyType::P256,
+        }
+    }
+
+    /// Recovers the public key which signed the 32-byte digest `msg`.  Only
+    /// secp256k1 signatures are recoverable, for other key types
+    /// [`RecoverError::NotRecoverable`](crate::errors::RecoverError::NotRecoverable)
+    /// is returned.
+    pub fn recover_public_key(
+        &self,
+        msg: impl Into<Digest32>,
+    ) -> Result<PublicKey, crate::errors::RecoverError> {
+        match self {
+            Signature::SECP256K1(signature) => {
+                signature.recover(msg).map(PublicKey::SECP256K1).map_err(|err| {
+                    crate::errors::RecoverError::InvalidSignature { error_message: err.to_string() }
+                })
+            }
+            _ => Err(crate::errors::RecoverError::NotRecoverable { key_type: self.key_type() }),
+        }
+    }
+
+    /// Recovers the signer of `data` in one step, i.e. the public key this
+    /// signature verifies `data` with.  Successful recovery proves that the
+    /// signature is valid for the returned key so there's no need to call
+    /// [`Signature::verify`] as well; whether the key is the expected one is
+    /// up to the caller to check.
+    ///
+    /// Only secp256k1 signatures over a 32-byte digest are recoverable, for
+    /// Ed25519, RSA and P-256 signatures this always returns `None`.
+    pub fn verify_and_recover(&self, data: &[u8]) -> Option<PublicKey> {
+        let msg = <[u8; 32]>::try_from(data).ok()?;
+        self.recover_public_key(msg).ok()
+    }
+}
+
+/// Same as [`Signature::try_from_tagged`].
+impl TryFrom<&[u8]> for Signature {
+    type Error = crate::errors::ParseSignatureError;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        Self::try_from_tagged(data)
+    }
+}
+
+impl Default for Signature {
+    fn default() -> Self {
+        Signature::empty(KeyType::default())
+    }
+}
+
+impl BorshSerialize for Signature {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
+        match self {
+            Signature::ED25519(signature) => {
+                BorshSerialize::serialize(&0u8, writer)?;
+                writer.write_all(&signature.to_bytes())?;
+            }
+            Signature::SECP256K1(signature) => {
+                BorshSerialize::serialize(&1u8, writer)?;
+                writer.write_all(&signature.0)?;
+            }
+            Signature::RSA(signature) => {
+                BorshSerialize::serialize(&2u8, writer)?;
+                writer.write_all(&signature.0)?;
+            }
+            Signature::P256(signature) => {
+                BorshSerialize::serialize(&3u8, writer)?;
+                writer.write_all(&signature.0)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl BorshDeserialize for Signature {
+    fn deserialize_reader<R: Read>(rd: &mut R) -> std::io::Result<Self> {
+        let tag = u8::deserialize_reader(rd)?;
+        let key_type = KeyType::try_from(tag)
+            .map_err(|err| Error::new(ErrorKind::InvalidData, err.to_string()))?;
+        match key_type {
+            KeyType::ED25519 => {
+                let array: [u8; ed25519_dalek::SIGNATURE_LENGTH] =
+                    BorshDeserialize::deserialize_reader(rd)?;
+                ed25519_signature_from_bytes(&array)
+                    .map(Signature::ED25519)
+                    .ok_or_else(|| Error::new(ErrorKind::InvalidData, "signature error"))
+            }
+            KeyType::SECP256K1 => {
+                let array: [u8; 65] = BorshDeserialize::deserialize_reader(rd)?;
+                Ok(Signature::SECP256K1(Secp256K1Signature(array)))
+            }
+            KeyType::RSA2048 => {
+                let array: [u8; 256] = BorshDeserialize::deserialize_reader(rd)?;
+                Ok(Signature::RSA(Rsa2048Signature(array)))
+            }
+            KeyType::P256 => {
+                let array: [u8; P256_SIGNATURE_LENGTH] = BorshDeserialize::deserialize_reader(rd)?;
+                Ok(Signature::P256(P256Signature(array)))
+            }
+        }
+    }
+}
+
+impl Display for Signature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let buf;
+        let (key_type, key_data) = match self {
+            Signature::ED25519(signature) => {
+                buf = signature.to_bytes();
+                (KeyType::ED25519, &buf[..])
+            }
+            Signature::SECP256K1(signature) => (KeyType::SECP256K1, &signature.0[..]),
+            Signature::RSA(signature) => (KeyType::RSA2048, &signature.0[..]),
+            Signature::P256(signature) => (KeyType::P256, &signature.0[..]),
+        };
+        write!(f, "{}:{}", key_type, Bs58(&key_data))
+    }
+}
+
+impl Debug for Signature {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        Display::fmt(self, f)
+    }
+}
+
+/// Serializes the signature as the `keytype:base58` string in human-readable
+/// formats (e.g. JSON) and as the tagged raw bytes (same as Borsh) in binary
+/// formats (e.g. bincode).
+impl serde::Serialize for Signature {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> Result<<S as serde::Serializer>::Ok, <S as serde::Serializer>::Error>
+    where
+        S: serde::Serializer,
+    {
+        serialize_str_or_bytes(self, serializer)
+    }
+}
+
+impl FromStr for Signature {
+    type Err = crate::errors::ParseSignatureError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (sig_type, sig_data) = split_key_type_data(value)?;
+        Ok(match sig_type {
+            KeyType::ED25519 => {
+                let data = decode_bs58::<{ ed25519_dalek::SIGNATURE_LENGTH }>(sig_data)?;
+                Signature::ED25519(ed25519_signature_from_bytes(&data).ok_or_else(|| {
+                    crate::errors::ParseSignatureError::InvalidData {
+                        error_message: "invalid ED25519 signature".to_string(),
+                    }
+                })?)
+            }
+            KeyType::SECP256K1 => Signature::SECP256K1(Secp256K1Signature(decode_bs58(sig_data)?)),
+            KeyType::RSA2048 => Signature::RSA(Rsa2048Signature(decode_bs58(sig_data)?)),
+            KeyType::P256 => Signature::P256(P256Signature(decode_bs58(sig_data)?)),
+        })
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Signature {
+    fn deserialize<D>(deserializer: D) -> Result<Self, <D as serde::Deserializer<'de>>::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserialize_str_or_bytes(deserializer)
+    }
+}
+
+/// Serializes a key or a signature as its string representation in
+/// human-readable formats (e.g. JSON) and as its Borsh bytes in binary formats
+/// (e.g. bincode).
+fn serialize_str_or_bytes<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+    T: Display + BorshSerialize,
+{
+    if serializer.is_human_readable() {
+        serializer.collect_str(value)
+    } else {
+        serializer.serialize_bytes(&borsh::to_vec(value).map_err(serde::ser::Error::custom)?)
+    }
+}
+
+/// Deserializes a key or a signature from either its string or its Borsh bytes
+/// representation, whichever the format provides.
+///
+/// Binary formats used to store the string representation, so bytes which
+/// aren't valid Borsh are also accepted if they are a UTF-8 string which
+/// parses.
+fn deserialize_str_or_bytes<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: FromStr + BorshDeserialize,
+    T::Err: Display,
+{
+    struct StrOrBytesVisitor<T>(std::marker::PhantomData<T>);
+
+    impl<'de, T> serde::de::Visitor<'de> for StrOrBytesVisitor<T>
+    where
+        T: FromStr + BorshDeserialize,
+        T::Err: Display,
+    {
+        type Value = T;
+
+        fn expecting(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            f.write_str("a base58 string or Borsh bytes")
+        }
+
+        fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<T, E> {
+            value.parse().map_err(|err: T::Err| E::custom(err.to_string()))
+        }
+
+        fn visit_bytes<E: serde::de::Error>(self, value: &[u8]) -> Result<T, E> {
+            T::try_from_slice(value).or_else(|err| {
+                std::str::from_utf8(value)
+                    .ok()
+                    .and_then(|value| value.parse().ok())
+                    .ok_or_else(|| E::custom(err.to_string()))
+            })
+        }
+
+        fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<T, A::Error> {
+            let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(byte) = seq.next_element::<u8>()? {
+                bytes.push(byte);
+            }
+            self.visit_bytes(&bytes)
+        }
+    }
+
+    let visitor = StrOrBytesVisitor(std::marker::PhantomData);
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(visitor)
+    } else {
+        deserializer.deserialize_bytes(visitor)
+    }
+}
+
+/// Helper struct which provides Display implementation for bytes slice
+/// encoding them using base58.
+// TODO(mina86): Get rid of it once bs58 has this feature.  There’s currently PR
+// for that: https://github.com/Nullus157/bs58-rs/pull/97
+struct Bs58<'a>(&'a [u8]);
+
+/// Size of the stack buffer used by [`Bs58`].  Fits encoding of up to 92
+/// bytes which covers ED25519 keys and all non-RSA signatures while keeping
+/// the stack frame small.
+const BS58_STACK_BUFFER_LENGTH: usize = 128;
+
+impl<'a> core::fmt::Display for Bs58<'a> {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // Base58 increases size of the value by less than 38% (log(256) /
+        // log(58) ≈ 1.366) so this is enough to fit any encoded value.  Small
+        // keys and signatures, which are what gets formatted most often, fit
+        // on the stack; anything larger than that (e.g. RSA keys) is encoded
+        // into a heap buffer instead.
+        let max_len = (self.0.len().saturating_mul(138) / 100).saturating_add(1);
+        let mut stack_buf = [0u8; BS58_STACK_BUFFER_LENGTH];
+        let mut heap_buf;
+        let buf = if max_len <= stack_buf.len() {
+            &mut stack_buf[..]
+        } else {
+            heap_buf = vec![0u8; max_len];
+            &mut heap_buf[..]
+        };
+        let len = bs58::encode(self.0).into(&mut buf[..]).unwrap();
+        let output = &buf[..len];
+        // SAFETY: we know that alphabet can only include ASCII characters
+        // thus our result is an ASCII string.
+        fmt.write_str(unsafe { std::str::from_utf8_unchecked(output) })
+    }
+}
+
+/// Helper which decodes fixed-length base58-encoded data.
+///
+/// If the encoded string decodes into a buffer of different length than `N`,
+/// returns error.  Similarly returns error if decoding fails.
+fn decode_bs58<const N: usize>(encoded: &str) -> Result<[u8; N], DecodeBs58Error> {
+    let mut buffer = [0u8; N];
+    decode_bs58_impl(&mut buffer[..], encoded)?;
+    Ok(buffer)
+}
+
+fn decode_bs58_impl(dst: &mut [u8], encoded: &str) -> Result<(), DecodeBs58Error> {
+    let expected = dst.len();
+    match bs58::decode(encoded).into(dst) {
+        Ok(received) if received == expected => Ok(()),
+        Ok(received) => Err(DecodeBs58Error::BadLength { expected, received }),
+        Err(bs58::decode::Error::BufferTooSmall) => Err(too_long_error(expected, encoded)),
+        Err(err) => Err(DecodeBs58Error::BadData(err.to_string())),
+    }
+}
+
+/// Constructs error for `encoded` data which doesn't fit into `expected`
+/// bytes.  The data is decoded again into a heap buffer so that the error
+/// reports the actual decoded length.  The buffer is wiped afterwards since
+/// the data may be a secret key.
+fn too_long_error(expected: usize, encoded: &str) -> DecodeBs58Error {
+    match bs58::decode(encoded).into_vec().map(Zeroizing::new) {
+        Ok(data) => DecodeBs58Error::BadLength { expected, received: data.len() },
+        Err(err) => DecodeBs58Error::BadData(err.to_string()),
+    }
+}
+
+/// Decodes variable-length secret key material.  The buffer is wiped once
+/// dropped, including when decoding fails.
+fn parse_bs58_data(max_len: usize, encoded: &str) -> Result<Zeroizing<Vec<u8>>, DecodeBs58Error> {
+    // N-byte encoded base58 string decodes to at most N bytes so there’s no
+    // need to allocate full max_len output buffer if encoded length is shorter.
+    let mut data = Zeroizing::new(vec![0u8; max_len.min(encoded.len())]);
+    let expected = data.len();
+    match bs58::decode(encoded.as_bytes()).into(data.as_mut_slice()) {
+        Ok(len) => {
+            data.truncate(len);
+            Ok(data)
+        }
+        Err(bs58::decode::Error::BufferTooSmall) => Err(too_long_error(expected, encoded)),
+        Err(err) => Err(DecodeBs58Error::BadData(err.to_string())),
+    }
+}
+
+enum DecodeBs58Error {
+    BadLength { expected: usize, received: usize },
+    BadData(String),
+    ChecksumMismatch,
+}
+
+impl std::convert::From<DecodeBs58Error> for crate::errors::ParseKeyError {
+    fn from(err: DecodeBs58Error) -> Self {
+        match err {
+            DecodeBs58Error::BadLength { expected, received } => {
+                crate::errors::ParseKeyError::InvalidLength {
+                    expected_length: expected,
+                    received_length: received,
+                }
+            }
+            DecodeBs58Error::BadData(error_message) => Self::InvalidData { error_message },
+            DecodeBs58Error::ChecksumMismatch => Self::ChecksumMismatch,
+        }
+    }
+}
+
+impl std::convert::From<DecodeBs58Error> for crate::errors::ParseSignatureError {
+    fn from(err: DecodeBs58Error) -> Self {
+        match err {
+            DecodeBs58Error::BadLength { expected, received } => {
+                Self::InvalidLength { expected_length: expected, received_length: received }
+            }
+            DecodeBs58Error::BadData(error_message) => Self::InvalidData { error_message },
+            DecodeBs58Error::ChecksumMismatch => Self::ChecksumMismatch,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_verify() {
+        for key_type in [KeyType::ED25519, KeyType::SECP256K1, KeyType::RSA2048, KeyType::P256] {
+            let secret_key = SecretKey::from_random(key_type);
+            let public_key = secret_key.public_key();
+            use sha2::Digest;
+            let data = sha2::Sha256::digest(b"123").to_vec();
+            let signature = secret_key.sign(&data);
+            assert!(signature.verify(&data, &public_key));
+        }
+    }
+
+    #[test]
+    fn signature_verify_fuzzer() {
+        bolero::check!().with_type().for_each(
+            |(key_type, sign, data, public_key): &(KeyType, [u8; 65], Vec<u8>, PublicKey)| {
+                // RSA signatures are longer than the generated bytes, pad them.
+                let mut signature_data = [0u8; RSA2048_SIGNATURE_LENGTH];
+                signature_data[..sign.len()].copy_from_slice(sign);
+                let len = key_type.signature_len().unwrap();
+                // Not all byte strings are signatures, e.g. ED25519 ones with
+                // the top bits set; only verification mustn't panic.
+                if let Ok(signature) = Signature::from_parts(*key_type, &signature_data[..len]) {
+                    let _ = signature.verify(data, public_key);
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn string_roundtrip_fuzzer() {
+        bolero::check!().with_type().for_each(|public_key: &PublicKey| {
+            let value = public_key.to_string();
+            assert_eq!(&PublicKey::from_str(&value).unwrap(), public_key, "{value}");
+        });
+
+        bolero::check!().with_type().for_each(|(key_type, data): &(KeyType, Vec<u8>)| {
+            let mut data = data.clone();
+            data.resize(key_type.signature_len().unwrap(), 0);
+            // Not every byte string is a valid ED25519 signature.
+            let Ok(signature) = Signature::from_parts(*key_type, &data) else { return };
+            let value = signature.to_string();
+            assert_eq!(Signature::from_str(&value).unwrap(), signature, "{value}");
+        });
+
+        // RSA keys are left out, generating them is too slow for a fuzzer.
+        bolero::check!().with_type().for_each(|(key_type, seed): &(KeyType, [u8; 32])| {
+            let secret_key = match key_type {
+                KeyType::ED25519 => SecretKey::ED25519(ED25519SecretKey::from_seed_bytes(seed)),
+                KeyType::SECP256K1 => match secp256k1::SecretKey::from_slice(seed) {
+                    Ok(secret_key) => SecretKey::SECP256K1(secret_key),
+                    Err(_) => return,
+                },
+                KeyType::RSA2048 => return,
+                KeyType::P256 => match p256::ecdsa::SigningKey::from_slice(seed) {
+                    Ok(secret_key) => SecretKey::P256(secret_key),
+                    Err(_) => return,
+                },
+            };
+            assert_eq!(SecretKey::from_str(&secret_key.to_string()).unwrap(), secret_key);
+        });
+    }
+
+    #[test]
+    fn from_str_never_panics_fuzzer() {
+        bolero::check!().with_type().for_each(|value: &String| {
+            let _ = KeyType::from_str(value);
+            let _ = PublicKey::from_str(value);
+            let _ = PublicKey::from_str_strict(value);
+            let _ = PublicKey::from_uri(value);
+            let _ = SecretKey::from_str(value);
+            let _ = Signature::from_str(value);
+            let _ = Signature::from_str_strict(value);
+        });
+    }
+
+    #[test]
+    fn regression_signature_verification_originally_failed() {
+        let signature = Signature::from_parts(KeyType::SECP256K1, &[4; 65]).unwrap();
+        let _ = signature.verify(&[], &PublicKey::empty(KeyType::SECP256K1));
+    }
+
+    #[test]
+    fn test_secp256k1_recovery_id_range() {
+        use sha2::Digest;
+        let data = sha2::Sha256::digest(b"123");
+        let sk = SecretKey::from_seed(KeyType::SECP256K1, "test");
+        let public_key = sk.public_key();
+        let Signature::SECP256K1(signature) = sk.sign(&data) else { unreachable!() };
+
+        for recovery_id in 0..=3 {
+            let mut signature = signature.clone();
+            signature.0[64] = recovery_id;
+            assert!(Signature::SECP256K1(signature).verify(&data, &public_key), "{recovery_id}");
+        }
+        for recovery_id in 4..=u8::MAX {
+            let mut signature = signature.clone();
+            signature.0[64] = recovery_id;
+            let signature = Signature::SECP256K1(signature);
+            assert!(!signature.verify(&data, &public_key), "{recovery_id}");
+            assert!(
+                matches!(
+                    signature.verify_detailed(&data, &public_key),
+                    Err(crate::errors::VerifyFailure::MalformedSignature)
+                ),
+                "{recovery_id}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_secp256k1_is_degenerate() {
+        use sha2::Digest;
+        let Signature::SECP256K1(bad) =
+            Signature::from_parts(KeyType::SECP256K1, &[4; 65]).unwrap()
+        else {
+            unreachable!()
+        };
+        assert!(bad.is_degenerate());
+
+        let data = sha2::Sha256::digest(b"123");
+        let sk = SecretKey::from_seed(KeyType::SECP256K1, "test");
+        let Signature::SECP256K1(signature) = sk.sign(&data) else { unreachable!() };
+        assert!(!signature.is_degenerate());
+        assert!(Signature::SECP256K1(signature.clone()).verify(&data, &sk.public_key()));
+
+        let mut zero_r = signature.clone();
+        zero_r.0[0..32].fill(0);
+        assert!(zero_r.is_degenerate());
+        let mut zero_s = signature.clone();
+        zero_s.0[32..64].fill(0);
+        assert!(zero_s.is_degenerate());
+        let mut overflowing_s = signature.clone();
+        overflowing_s.0[32..64].fill(0xff);
+        assert!(overflowing_s.is_degenerate());
+        let mut bad_recovery_id = signature;
+        bad_recovery_id.0[64] = 4;
+        assert!(bad_recovery_id.is_degenerate());
+    }
+
+    #[test]
+    fn test_key_type_default() {
+        assert!(matches!(KeyType::default(), KeyType::ED25519));
+        assert_eq!(Signature::default().key_type() as u8, KeyType::default() as u8);
+        let pk = PublicKey::from_seed(KeyType::ED25519, "test");
+        let untagged = pk.to_string().split_once(':').unwrap().1.to_string();
+        assert_eq!(
+            PublicKey::from_str(&untagged).unwrap().key_type() as u8,
+            KeyType::default() as u8
+        );
+    }
+
+    #[test]
+    fn test_key_type_serde() {
+        assert_eq!(serde_json::to_string(&KeyType::ED25519).unwrap(), "\"ed25519\"");
+        for key_type in [KeyType::ED25519, KeyType::SECP256K1, KeyType::RSA2048, KeyType::P256] {
+            let json = serde_json::to_string(&key_type).unwrap();
+            assert_eq!(json, format!("\"{key_type}\""));
+            let parsed: KeyType = serde_json::from_str(&json).unwrap();
+            assert_eq!(parsed as u8, key_type as u8);
+        }
+        let legacy: KeyType = serde_json::from_str("\"SECP256K1\"").unwrap();
+        assert!(matches!(legacy, KeyType::SECP256K1));
+        assert!(serde_json::from_str::<KeyType>("\"ed448\"").is_err());
+    }
+
+    #[test]
+    fn test_parse_with_public_key() {
+        for key_type in [KeyType::ED25519, KeyType::SECP256K1, KeyType::RSA2048, KeyType::P256] {
+            let sk = SecretKey::from_seed(key_type, "test");
+            let (parsed_sk, pk) = SecretKey::parse_with_public_key(&sk.to_string()).unwrap();
+            assert_eq!(parsed_sk, sk, "{key_type}");
+            assert_eq!(pk, PublicKey::from_seed(key_type, "test"), "{key_type}");
+        }
+        assert!(SecretKey::parse_with_public_key("ed25519:0").is_err());
+    }
+
+    #[test]
+    fn test_borsh_len() {
+        let data = [7u8; 32];
+        for key_type in [KeyType::ED25519, KeyType::SECP256K1, KeyType::RSA2048, KeyType::P256] {
+            let sk = SecretKey::from_seed(key_type, "test");
+            let pk = sk.public_key();
+            assert_eq!(pk.borsh_len(), borsh::to_vec(&pk).unwrap().len(), "{key_type}");
+            let signature = sk.sign(&data);
+            assert_eq!(
+                signature.borsh_len(),
+                borsh::to_vec(&signature).unwrap().len(),
+                "{key_type}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_json_deserialize_untagged() {
+        let sk = SecretKey::from_seed(KeyType::ED25519, "test");
+        let pk = sk.public_key();
+        let signature = sk.sign(b"123");
+        let untagged = |value: String| {
+            let (_, data) = value.split_once(':').unwrap();
+            format!("\"{data}\"")
+        };
+
+        let json = untagged(pk.to_string());
+        assert_eq!(serde_json::from_str::<PublicKey>(&json).unwrap(), pk);
+        let json = untagged(sk.to_string());
+        assert_eq!(serde_json::from_str::<SecretKey>(&json).unwrap(), sk);
+        let json = untagged(signature.to_string());
+        assert_eq!(serde_json::from_str::<Signature>(&json).unwrap(), signature);
+
+        // Untagged data of other key types is read as ED25519 and hence fails.
+        let sk = SecretKey::from_seed(KeyType::SECP256K1, "test");
+        let json = untagged(sk.public_key().to_string());
+        assert!(serde_json::from_str::<PublicKey>(&json).is_err());
+        let json = untagged(sk.to_string());
+        assert!(serde_json::from_str::<SecretKey>(&json).is_err());
+        let json = untagged(sk.sign(&[7; 32]).to_string());
+        assert!(serde_json::from_str::<Signature>(&json).is_err());
+    }
+
+    #[test]
+    fn test_json_serialize_ed25519() {
+        let sk = SecretKey::from_seed(KeyType::ED25519, "test");
+        let pk = sk.public_key();
+        let expected = "\"ed25519:DcA2MzgpJbrUATQLLceocVckhhAqrkingax4oJ9kZ847\"";
+        assert_eq!(serde_json::to_string(&pk).unwrap(), expected);
+        assert_eq!(pk, serde_json::from_str(expected).unwrap());
+        assert_eq!(
+            pk,
+            serde_json::from_str("\"DcA2MzgpJbrUATQLLceocVckhhAqrkingax4oJ9kZ847\"").unwrap()
+        );
+        let pk2: PublicKey = pk.to_string().parse().unwrap();
+        assert_eq!(pk, pk2);
+
+        let expected = "\"ed25519:3KyUuch8pYP47krBq4DosFEVBMR5wDTMQ8AThzM8kAEcBQEpsPdYTZ2FPX5ZnSoLrerjwg66hwwJaW1wHzprd5k3\"";
+        assert_eq!(serde_json::to_string(&sk).unwrap(), expected);
+        assert_eq!(sk, serde_json::from_str(expected).unwrap());
+
+        let signature = sk.sign(b"123");
+        let expected = "\"ed25519:3s1dvZdQtcAjBksMHFrysqvF63wnyMHPA4owNQmCJZ2EBakZEKdtMsLqrHdKWQjJbSRN6kRknN2WdwSBLWGCokXj\"";
+        assert_eq!(serde_json::to_string(&signature).unwrap(), expected);
+        assert_eq!(signature, serde_json::from_str(expected).unwrap());
+        let signature_str: String = signature.to_string();
+        let signature2: Signature = signature_str.parse().unwrap();
+        assert_eq!(signature, signature2);
+    }
+
+    #[test]
+    fn test_borsh_serialization() {
+        use sha2::Digest;
+        let data = sha2::Sha256::digest(b"123").to_vec();
+        for key_type in [KeyType::ED25519, KeyType::SECP256K1, KeyType::RSA2048, KeyType::P256] {
+            let sk = SecretKey::from_seed(key_type, "test");
+            let pk = sk.public_key();
+            let bytes = borsh::to_vec(&pk).unwrap();
+            assert_eq!(PublicKey::try_from_slice(&bytes).unwrap(), pk);
+
+            let signature = sk.sign(&data);
+            let bytes = borsh::to_vec(&signature).unwrap();
+            assert_eq!(Signature::try_from_slice(&bytes).unwrap(), signature);
+
+            assert!(PublicKey::try_from_slice(&[0]).is_err());
+            assert!(Signature::try_from_slice(&[0]).is_err());
+        }
+    }
+
+    #[test]
+    fn test_sign_to_string_verify_str() {
+        use sha2::Digest;
+        let data = sha2::Sha256::digest(b"123").to_vec();
+        for key_type in [KeyType::ED25519, KeyType::SECP256K1] {
+            let sk = SecretKey::from_seed(key_type, "test");
+            let pk = sk.public_key();
+            let signature = sk.sign_to_string(&data);
+            assert_eq!(signature, sk.sign(&data).to_string());
+            assert!(pk.verify_str(&data, &signature).unwrap());
+            assert!(!pk.verify_str(b"456", &signature).unwrap());
+        }
+        let pk = PublicKey::from_seed(KeyType::ED25519, "test");
+        assert!(pk.verify_str(&data, "ed25519:invalid").is_err());
+    }
+
+    #[test]
+    fn test_matches_public_key() {
+        for key_type in [KeyType::ED25519, KeyType::SECP256K1, KeyType::RSA2048, KeyType::P256] {
+            let sk = SecretKey::from_seed(key_type, "test");
+            assert!(sk.matches_public_key(&sk.public_key()));
+            assert!(!sk.matches_public_key(&PublicKey::empty(key_type)));
+        }
+        let sk = SecretKey::from_seed(KeyType::ED25519, "test");
+        assert!(!sk.matches_public_key(&PublicKey::from_seed(KeyType::ED25519, "other")));
+        assert!(!sk.matches_public_key(&PublicKey::from_seed(KeyType::SECP256K1, "test")));
+    }
+
+    #[test]
+    fn test_bs58_display_large_input() {
+        for len in [0, 1, 32, 64, 65, 92, 93, 256, 294, 2048, 4096] {
+            let data: Vec<u8> = (0..len).map(|i| (i % 251) as u8).collect();
+            assert_eq!(Bs58(&data).to_string(), bs58::encode(&data).into_string());
+        }
+        let zeros = [0u8; 3000];
+        assert_eq!(Bs58(&zeros).to_string(), "1".repeat(3000));
+        // Inputs around the stack buffer limit which encode to the longest
+        // possible output for their length.
+        for len in 90..=94 {
+            let data = vec![0xff; len];
+            assert_eq!(Bs58(&data).to_string(), bs58::encode(&data).into_string());
+            let zeros = vec![0; len];
+            assert_eq!(Bs58(&zeros).to_string(), "1".repeat(len));
+        }
+        for key_type in [KeyType::ED25519, KeyType::SECP256K1, KeyType::RSA2048, KeyType::P256] {
+            let sk = SecretKey::from_seed(key_type, "test");
+            let pk = sk.public_key();
+            let expected = format!("{key_type}:{}", bs58::encode(pk.key_data()).into_string());
+            assert_eq!(pk.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn test_binary_serde() {
+        use sha2::Digest;
+        let data = sha2::Sha256::digest(b"123").to_vec();
+        for key_type in [KeyType::ED25519, KeyType::SECP256K1, KeyType::P256] {
+            let sk = SecretKey::from_seed(key_type, "test");
+            let pk = sk.public_key();
+            let bytes = bincode::serialize(&pk).unwrap();
+            assert!(bytes.ends_with(&borsh::to_vec(&pk).unwrap()));
+            assert!(bytes.len() < bincode::serialize(&pk.to_string()).unwrap().len());
+            assert_eq!(bincode::deserialize::<PublicKey>(&bytes).unwrap(), pk);
+
+            let signature = sk.sign(&data);
+            let bytes = bincode::serialize(&signature).unwrap();
+            assert!(bytes.ends_with(&borsh::to_vec(&signature).unwrap()));
+            assert_eq!(bincode::deserialize::<Signature>(&bytes).unwrap(), signature);
+
+            // Human-readable formats still use the string representation.
+            assert_eq!(serde_json::to_string(&pk).unwrap(), format!("\"{}\"", pk));
+
+            // Values previously stored as strings remain readable.
+            let legacy = bincode::serialize(&pk.to_string()).unwrap();
+            assert_eq!(bincode::deserialize::<PublicKey>(&legacy).unwrap(), pk);
+            let legacy = bincode::serialize(&signature.to_string()).unwrap();
+            assert_eq!(bincode::deserialize::<Signature>(&legacy).unwrap(), signature);
+        }
+        assert!(bincode::deserialize::<PublicKey>(&bincode::serialize(&[0u8]).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_verify_checked() {
+        use sha2::Digest;
+        let data = sha2::Sha256::digest(b"123").to_vec();
+        let sk = SecretKey::from_seed(KeyType::SECP256K1, "test");
+        let pk = sk.public_key();
+        let signature = sk.sign(&data);
+        assert!(signature.verify_checked(&data, &pk).unwrap());
+        assert!(!signature.verify(&data[..10], &pk));
+        assert!(matches!(
+            signature.verify_checked(&data[..10], &pk),
+            Err(crate::errors::VerifyError::InvalidMessageLength {
+                expected_length: 32,
+                received_length: 10
+            })
+        ));
+
+        let sk = SecretKey::from_seed(KeyType::ED25519, "test");
+        let signature = sk.sign(&data[..10]);
+        assert!(signature.verify_checked(&data[..10], &sk.public_key()).unwrap());
+    }
+
+    #[test]
+    fn test_from_str_strict() {
+        let sk = SecretKey::from_seed(KeyType::ED25519, "test");
+        let pk = sk.public_key();
+        let signature = sk.sign(b"123");
+
+        assert_eq!(PublicKey::from_str_strict(&pk.to_string()).unwrap(), pk);
+        assert_eq!(SecretKey::from_str_strict(&sk.to_string()).unwrap(), sk);
+        assert_eq!(Signature::from_str_strict(&signature.to_string()).unwrap(), signature);
+
+        let untagged = |s: String| s.split_once(':').unwrap().1.to_string();
+        assert_eq!(PublicKey::from_str(&untagged(pk.to_string())).unwrap(), pk);
+        assert!(matches!(
+            PublicKey::from_str_strict(&untagged(pk.to_string())),
+            Err(crate::errors::ParseKeyError::MissingKeyTypePrefix)
+        ));
+        assert!(matches!(
+            SecretKey::from_str_strict(&untagged(sk.to_string())),
+            Err(crate::errors::ParseKeyError::MissingKeyTypePrefix)
+        ));
+        assert!(matches!(
+            Signature::from_str_strict(&untagged(signature.to_string())),
+            Err(crate::errors::ParseSignatureError::MissingKeyTypePrefix)
+        ));
+
+        let empty_prefix = |s: String| format!(":{}", untagged(s));
+        assert!(matches!(
+            PublicKey::from_str_strict(&empty_prefix(pk.to_string())),
+            Err(crate::errors::ParseKeyError::MissingKeyTypePrefix)
+        ));
+        assert!(matches!(
+            SecretKey::from_str_strict(&empty_prefix(sk.to_string())),
+            Err(crate::errors::ParseKeyError::MissingKeyTypePrefix)
+        ));
+        assert!(matches!(
+            Signature::from_str_strict(&format!(" {}", empty_prefix(signature.to_string()))),
+            Err(crate::errors::ParseSignatureError::MissingKeyTypePrefix)
+        ));
+    }
+
+    #[test]
+    fn test_verify_detailed() {
+        use crate::errors::VerifyFailure;
+        use sha2::Digest;
+        let data = sha2::Sha256::digest(b"123").to_vec();
+
+        let sk = SecretKey::from_seed(KeyType::SECP256K1, "test");
+        let pk = sk.public_key();
+        let signature = sk.sign(&data);
+        assert!(signature.verify_detailed(&data, &pk).is_ok());
+        assert!(matches!(
+            signature.verify_detailed(&data, &PublicKey::from_seed(KeyType::SECP256K1, "other")),
+            Err(VerifyFailure::SignatureMismatch)
+        ));
+        assert!(matches!(
+            signature.verify_detailed(&data, &PublicKey::empty(KeyType::SECP256K1)),
+            Err(VerifyFailure::InvalidPublicKey)
+        ));
+        assert!(matches!(
+            signature.verify_detailed(&data[..10], &pk),
+            Err(VerifyFailure::InvalidMessage)
+        ));
+        assert!(matches!(
+            signature.verify_detailed(&data, &PublicKey::empty(KeyType::ED25519)),
+            Err(VerifyFailure::KeyTypeMismatch {
+                signature_key_type: KeyType::SECP256K1,
+                public_key_type: KeyType::ED25519,
+            })
+        ));
+        let Signature::SECP256K1(mut malformed) = signature else { unreachable!() };
+        malformed.0[64] = 4;
+        assert!(matches!(
+            Signature::SECP256K1(malformed).verify_detailed(&data, &pk),
+            Err(VerifyFailure::MalformedSignature)
+        ));
+
+        let sk = SecretKey::from_seed(KeyType::ED25519, "test");
+        let signature = sk.sign(&data);
+        assert!(signature.verify_detailed(&data, &sk.public_key()).is_ok());
+        assert!(matches!(
+            signature.verify_detailed(b"456", &sk.public_key()),
+            Err(VerifyFailure::SignatureMismatch)
+        ));
+
+        let sk = SecretKey::from_seed(KeyType::P256, "test");
+        let pk = sk.public_key();
+        assert!(matches!(
+            sk.sign(&data).verify_detailed(&data, &PublicKey::empty(KeyType::P256)),
+            Err(VerifyFailure::InvalidPublicKey)
+        ));
+        assert!(matches!(
+            Signature::from_parts(KeyType::P256, &[0; 64]).unwrap().verify_detailed(&data, &pk),
+            Err(VerifyFailure::MalformedSignature)
+        ));
+
+        assert!(matches!(
+            Signature::from_parts(KeyType::RSA2048, &[0; 256])
+                .unwrap()
+                .verify_detailed(&data, &PublicKey::empty(KeyType::RSA2048)),
+            Err(VerifyFailure::InvalidPublicKey)
+        ));
+    }
+
+    #[test]
+    fn test_invalid_data() {
+        let invalid = "\"secp256k1:2xVqteU8PWhadHTv99TGh3bSf\"";
+        assert!(serde_json::from_str::<PublicKey>(invalid).is_err());
+        assert!(serde_json::from_str::<SecretKey>(invalid).is_err());
+        assert!(serde_json::from_str::<Signature>(invalid).is_err());
+    }
+
+    #[test]
+    fn test_public_key_from_secret_bytes() {
+        for key_type in [KeyType::ED25519, KeyType::SECP256K1, KeyType::RSA2048, KeyType::P256] {
+            let sk = SecretKey::from_seed(key_type, "test");
+            let bytes = match &sk {
+                SecretKey::ED25519(secret_key) => secret_key.0.to_vec(),
+                SecretKey::SECP256K1(secret_key) => secret_key.secret_bytes().to_vec(),
+                SecretKey::RSA(secret_key) => {
+                    secret_key.to_pkcs8_der().unwrap().as_bytes().to_vec()
+                }
+                SecretKey::P256(secret_key) => secret_key.to_bytes().to_vec(),
+            };
+            assert_eq!(public_key_from_secret_bytes(key_type, &bytes).unwrap(), sk.public_key());
+            assert!(public_key_from_secret_bytes(key_type, &bytes[1..]).is_err(), "{key_type}");
+        }
+
+        let sk = SecretKey::from_seed(KeyType::ED25519, "test");
+        let keypair = &sk.unwrap_as_ed25519().0;
+        assert_eq!(
+            public_key_from_secret_bytes(KeyType::ED25519, &keypair[..32]).unwrap(),
+            sk.public_key()
+        );
+        let mut mismatched = *keypair;
+        mismatched[63] ^= 1;
+        assert!(matches!(
+            public_key_from_secret_bytes(KeyType::ED25519, &mismatched),
+            Err(crate::errors::ParseKeyError::InvalidData { .. })
+        ));
+        assert!(matches!(
+            public_key_from_secret_bytes(KeyType::SECP256K1, &[0; 32]),
+            Err(crate::errors::ParseKeyError::InvalidData { .. })
+        ));
+    }
+
+    #[test]
+    fn test_from_random_batch() {
+        let key_pairs = SecretKey::from_random_batch(KeyType::ED25519, 100);
+        assert_eq!(key_pairs.len(), 100);
+        let public_keys: std::collections::HashSet<_> =
+            key_pairs.iter().map(|(_, public_key)| public_key.clone()).collect();
+        assert_eq!(public_keys.len(), 100);
+        for (secret_key, public_key) in &key_pairs {
+            assert_eq!(&secret_key.public_key(), public_key);
+        }
+
+        let key_pairs = SecretKey::from_random_batch(KeyType::RSA2048, 2);
+        assert_eq!(key_pairs.len(), 2);
+        assert_ne!(key_pairs[0].1, key_pairs[1].1);
+    }
+
+    #[test]
+    fn test_ed25519_dalek_conversions() {
+        let SecretKey::ED25519(secret_key) = SecretKey::from_seed(KeyType::ED25519, "test") else {
+            unreachable!()
+        };
+        let signing_key = ed25519_dalek::SigningKey::from(&secret_key);
+        assert_eq!(ED25519SecretKey::from(&signing_key), secret_key);
+
+        let PublicKey::ED25519(public_key) = SecretKey::ED25519(secret_key).public_key() else {
+            unreachable!()
+        };
+        let verifying_key = ed25519_dalek::VerifyingKey::try_from(&public_key).unwrap();
+        assert_eq!(verifying_key, signing_key.verifying_key());
+        assert_eq!(ED25519PublicKey::from(&verifying_key), public_key);
+
+        // y = 2 doesn't correspond to a point on the curve.
+        let mut off_curve = [0; ed25519_dalek::PUBLIC_KEY_LENGTH];
+        off_curve[0] = 2;
+        assert!(matches!(
+            ed25519_dalek::VerifyingKey::try_from(&ED25519PublicKey(off_curve)),
+            Err(crate::errors::ParseKeyError::InvalidData { .. })
+        ));
+    }
+
+    #[test]
+    fn test_public_key_is_zero() {
+        for key_type in [KeyType::ED25519, KeyType::SECP256K1, KeyType::RSA2048, KeyType::P256] {
+            assert!(PublicKey::empty(key_type).is_zero());
+            assert!(!PublicKey::from_seed(key_type, "test").is_zero());
+        }
+
+        let sk = SecretKey::from_seed(KeyType::ED25519, "test");
+        let signature = sk.sign(b"123");
+        assert!(matches!(
+            signature.verify_detailed(b"123", &PublicKey::empty(KeyType::ED25519)),
+            Err(crate::errors::VerifyFailure::InvalidPublicKey)
+        ));
+    }
+
+    #[test]
+    fn test_try_from_public_key() {
+        use crate::errors::KeyConversionError;
+
+        let ed25519 = PublicKey::from_seed(KeyType::ED25519, "test");
+        let secp256k1 = PublicKey::from_seed(KeyType::SECP256K1, "test");
+        let rsa = PublicKey::from_seed(KeyType::RSA2048, "test");
+        let p256 = PublicKey::from_seed(KeyType::P256, "test");
+
+        assert_eq!(<&ED25519PublicKey>::try_from(&ed25519).unwrap(), ed25519.unwrap_as_ed25519());
+        assert_eq!(
+            <&Secp256K1PublicKey>::try_from(&secp256k1).unwrap(),
+            secp256k1.unwrap_as_secp256k1()
+        );
+        assert_eq!(<&Rsa2048PublicKey>::try_from(&rsa).unwrap(), rsa.unwrap_as_rsa2048());
+        assert_eq!(<&P256PublicKey>::try_from(&p256).unwrap(), p256.unwrap_as_p256());
+
+        assert_eq!(PublicKey::from(ED25519PublicKey::try_from(ed25519.clone()).unwrap()), ed25519);
+        assert_eq!(
+            PublicKey::from(Secp256K1PublicKey::try_from(secp256k1.clone()).unwrap()),
+            secp256k1
+        );
+        assert_eq!(PublicKey::from(Rsa2048PublicKey::try_from(rsa.clone()).unwrap()), rsa);
+        assert_eq!(PublicKey::from(P256PublicKey::try_from(p256.clone()).unwrap()), p256);
+
+        assert!(matches!(
+            <&ED25519PublicKey>::try_from(&secp256k1),
+            Err(KeyConversionError::KeyTypeMismatch {
+                expected_key_type: KeyType::ED25519,
+                received_key_type: KeyType::SECP256K1,
+            })
+        ));
+        assert!(matches!(
+            Rsa2048PublicKey::try_from(p256),
+            Err(KeyConversionError::KeyTypeMismatch {
+                expected_key_type: KeyType::RSA2048,
+                received_key_type: KeyType::P256,
+            })
+        ));
+        assert!(Secp256K1PublicKey::try_from(ed25519).is_err());
+        assert!(<&P256PublicKey>::try_from(&rsa).is_err());
+    }
+
+    #[test]
+    fn test_secret_key_accessors() {
+        use crate::errors::KeyConversionError;
+
+        let ed25519 = SecretKey::from_seed(KeyType::ED25519, "test");
+        let secp256k1 = SecretKey::from_seed(KeyType::SECP256K1, "test");
+        let rsa = SecretKey::from_seed(KeyType::RSA2048, "test");
+        let p256 = SecretKey::from_seed(KeyType::P256, "test");
+
+        assert_eq!(ed25519.try_as_ed25519().unwrap().0, ed25519.unwrap_as_ed25519().0);
+        assert_eq!(secp256k1.try_as_secp256k1().unwrap(), secp256k1.unwrap_as_secp256k1());
+        assert_eq!(rsa.try_as_rsa2048().unwrap(), rsa.unwrap_as_rsa2048());
+        assert_eq!(p256.try_as_p256().unwrap(), p256.unwrap_as_p256());
+        assert_eq!(
+            SecretKey::SECP256K1(*secp256k1.unwrap_as_secp256k1()).public_key(),
+            secp256k1.public_key()
+        );
+
+        assert!(matches!(
+            ed25519.try_as_secp256k1(),
+            Err(KeyConversionError::KeyTypeMismatch {
+                expected_key_type: KeyType::SECP256K1,
+                received_key_type: KeyType::ED25519,
+            })
+        ));
+        assert!(matches!(
+            p256.try_as_rsa2048(),
+            Err(KeyConversionError::KeyTypeMismatch {
+                expected_key_type: KeyType::RSA2048,
+                received_key_type: KeyType::P256,
+            })
+        ));
+        assert!(secp256k1.try_as_ed25519().is_err());
+        assert!(rsa.try_as_p256().is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_secret_key_unwrap_as_secp256k1_panics() {
+        SecretKey::from_seed(KeyType::ED25519, "test").unwrap_as_secp256k1();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_secret_key_unwrap_as_rsa2048_panics() {
+        SecretKey::from_seed(KeyType::SECP256K1, "test").unwrap_as_rsa2048();
+    }
+
+    #[test]
+    fn test_public_key_from_uri() {
+        use crate::errors::ParseKeyError;
+
+        for key_type in [KeyType::ED25519, KeyType::SECP256K1, KeyType::RSA2048, KeyType::P256] {
+            let public_key = PublicKey::from_seed(key_type, "test");
+            let value = public_key.to_string();
+            assert_eq!(PublicKey::from_uri(&value).unwrap(), public_key);
+            assert_eq!(PublicKey::from_uri(&format!("key:{value}")).unwrap(), public_key);
+        }
+        let public_key = PublicKey::from_seed(KeyType::ED25519, "test");
+        let key_data = public_key.to_string().split_once(':').unwrap().1.to_string();
+        assert_eq!(PublicKey::from_uri(&key_data).unwrap(), public_key);
+        assert_eq!(PublicKey::from_uri(&format!("key:{key_data}")).unwrap(), public_key);
+
+        assert!(matches!(
+            PublicKey::from_uri(&format!("data:application/octet-stream;base58,{key_data}")),
+            Err(ParseKeyError::UnknownUriScheme { unknown_scheme }) if unknown_scheme == "data"
+        ));
+        assert!(matches!(
+            PublicKey::from_uri(&format!("key:foo:{key_data}")),
+            Err(ParseKeyError::UnknownUriScheme { unknown_scheme }) if unknown_scheme == "foo"
+        ));
+    }
+
+    #[test]
+    fn test_secret_key_from_str_errors() {
+        use crate::errors::ParseKeyError;
+
+        for key_type in [KeyType::ED25519, KeyType::SECP256K1, KeyType::RSA2048, KeyType::P256] {
+            let secret_key = SecretKey::from_seed(key_type, "test");
+            let value = secret_key.to_string();
+            assert_eq!(SecretKey::from_str(&value).unwrap(), secret_key);
+
+            let too_long = format!("{value}{}", &value[value.len() - 10..]);
+            let truncated = &value[..value.len() - 10];
+            let bad_data = format!("{key_type}:0OIl");
+            for invalid in [too_long.as_str(), truncated, &bad_data] {
+                assert!(SecretKey::from_str(invalid).is_err(), "{invalid}");
+            }
+        }
+        assert!(matches!(
+            SecretKey::from_str(&format!("ed25519:{}", Bs58(&[1u8; 65]))),
+            Err(ParseKeyError::InvalidLength { expected_length: 64, received_length: 65 })
+        ));
+        assert!(matches!(
+            SecretKey::from_str(&format!("secp256k1:{}", Bs58(&[0xffu8; 32]))),
+            Err(ParseKeyError::InvalidData { .. })
+        ));
+    }
+
+    #[test]
+    fn test_empty_signature() {
+        for key_type in [KeyType::ED25519, KeyType::SECP256K1, KeyType::RSA2048, KeyType::P256] {
+            let signature = Signature::empty(key_type);
+            assert_eq!(signature.key_type() as u8, key_type as u8);
+            let data = borsh::to_vec(&signature).unwrap();
+            assert_eq!(data.len(), 1 + key_type.signature_len().unwrap(), "{key_type}");
+            assert!(data[1..].iter().all(|byte| *byte == 0), "{key_type}");
+
+            let sk = SecretKey::from_seed(key_type, "test");
+            assert!(!signature.verify(&[0; 32], &sk.public_key()), "{key_type}");
+            assert!(!signature.verify(&[0; 32], &PublicKey::empty(key_type)), "{key_type}");
+        }
+        assert_eq!(Signature::default(), Signature::empty(KeyType::ED25519));
+    }
+
+    #[test]
+    fn test_debug_repr() {
+        for key_type in [KeyType::ED25519, KeyType::SECP256K1, KeyType::RSA2048, KeyType::P256] {
+            let public_key = PublicKey::from_seed(key_type, "test");
+            let repr = public_key.debug_repr();
+            assert!(repr.contains(&format!("key type: {key_type}")), "{repr}");
+            assert!(repr.contains(&public_key.to_string()), "{repr}");
+            assert!(repr.contains(&hex::encode(public_key.key_data())), "{repr}");
+            assert!(repr.contains(&format!("length: {} bytes", key_type.public_key_len())));
+        }
+    }
+
+    #[test]
+    fn test_secret_key_hash() {
+        use std::collections::HashSet;
+
+        let mut keys = HashSet::new();
+        for key_type in [KeyType::ED25519, KeyType::SECP256K1, KeyType::RSA2048, KeyType::P256] {
+            assert!(keys.insert(SecretKey::from_seed(key_type, "test")));
+            assert!(keys.insert(SecretKey::from_seed(key_type, "other")));
+            assert!(!keys.insert(SecretKey::from_seed(key_type, "test")));
+        }
+        assert_eq!(keys.len(), 8);
+
+        // Keypairs with the same secret half are equal and so must hash equally.
+        let sk = SecretKey::from_seed(KeyType::ED25519, "test");
+        let mut keypair = sk.unwrap_as_ed25519().clone();
+        keypair.0[ed25519_dalek::SECRET_KEY_LENGTH..].fill(0);
+        assert!(keys.contains(&SecretKey::ED25519(keypair)));
+    }
+
+    #[test]
+    fn test_verify_many() {
+        use sha2::Digest;
+        let messages: Vec<[u8; 32]> = (0u8..4).map(|i| sha2::Sha256::digest([i]).into()).collect();
+        for key_type in [KeyType::ED25519, KeyType::SECP256K1, KeyType::P256] {
+            let sk = SecretKey::from_seed(key_type, "test");
+            let public_key = sk.public_key();
+            let signatures: Vec<Signature> =
+                messages.iter().map(|message| sk.sign(message)).collect();
+            let mut items: Vec<(&[u8], &Signature)> = messages
+                .iter()
+                .zip(&signatures)
+                .map(|(message, signature)| (&message[..], signature))
+                .collect();
+            assert!(public_key.verify_many(&items));
+            assert!(public_key.verify_many(&[]));
+
+            items[2].1 = &signatures[1];
+            assert!(!public_key.verify_many(&items), "{key_type}");
+            assert!(!PublicKey::from_seed(key_type, "other").verify_many(&items[..1]));
+        }
+    }
+
+    #[test]
+    fn test_verify_strict() {
+        for key_type in [KeyType::ED25519, KeyType::SECP256K1, KeyType::P256] {
+            let sk = SecretKey::from_seed(key_type, "test");
+            let data = [7u8; 32];
+            let signature = sk.sign(&data);
+            assert!(signature.verify_strict(&data, &sk.public_key()));
+            assert!(!signature.verify_strict(&[8u8; 32], &sk.public_key()));
+        }
+
+        // Identity point as the public key with `R` also the identity and
+        // `s = 0` satisfies the verification equation for any message.
+        let mut identity = [0u8; 32];
+        identity[0] = 1;
+        let public_key = PublicKey::ED25519(ED25519PublicKey(identity));
+        let mut signature_data = [0u8; 64];
+        signature_data[..32].copy_from_slice(&identity);
+        let signature = Signature::from_parts(KeyType::ED25519, &signature_data).unwrap();
+        for data in [&b"123"[..], b"any other message"] {
+            assert!(signature.verify(data, &public_key));
+            assert!(!signature.verify_strict(data, &public_key));
+        }
+    }
+
+    #[test]
+    fn test_sign_verify_ref() {
+        use sha2::Digest;
+        let data: [u8; 32] = sha2::Sha256::digest(b"123").into();
+        for key_type in [KeyType::ED25519, KeyType::SECP256K1, KeyType::RSA2048, KeyType::P256] {
+            let sk = SecretKey::from_seed(key_type, "test");
+            let public_key = sk.public_key();
+            let owned = data.to_vec();
+            let signature = sk.sign_ref(&owned);
+            assert_eq!(signature, sk.sign_ref(data));
+            assert_eq!(signature, sk.sign(&data));
+            assert!(signature.verify_ref(data, &public_key));
+            assert!(signature.verify_ref(owned, &public_key));
+            assert!(signature.verify_ref(&data[..], &public_key));
+            assert!(!signature.verify_ref([0u8; 32], &public_key));
+        }
+    }
+
+    #[test]
+    fn test_key_data_eq() {
+        let p256 = PublicKey::from_seed(KeyType::P256, "test");
+        let secp256k1 = PublicKey::SECP256K1(Secp256K1PublicKey(p256.unwrap_as_p256().0));
+        assert_ne!(secp256k1, p256);
+        assert!(secp256k1.key_data_eq(&p256));
+        assert!(secp256k1.key_data_eq(&secp256k1));
+        assert!(!secp256k1.key_data_eq(&PublicKey::from_seed(KeyType::SECP256K1, "test")));
+        assert!(!secp256k1.key_data_eq(&PublicKey::from_seed(KeyType::ED25519, "test")));
+    }
+
+    #[test]
+    fn test_public_key_order_is_stable() {
+        let mut keys = vec![];
+        for seed in ["test", "other", "third"] {
+            for key_type in [KeyType::P256, KeyType::RSA2048, KeyType::SECP256K1, KeyType::ED25519]
+            {
+                keys.push(PublicKey::from_seed(key_type, seed));
+            }
+        }
+        keys.sort();
+        for pair in keys.windows(2) {
+            let (a, b) = (&pair[0], &pair[1]);
+            assert!(
+                (a.key_type() as u8, a.key_data()) < (b.key_type() as u8, b.key_data()),
+                "{a} {b}"
+            );
+        }
+
+        let mut round_tripped: Vec<PublicKey> = keys
+            .iter()
+            .map(|key| PublicKey::try_from_slice(&borsh::to_vec(key).unwrap()).unwrap())
+            .rev()
+            .collect();
+        round_tripped.sort();
+        assert_eq!(round_tripped, keys);
+
+        let mut parsed: Vec<PublicKey> =
+            keys.iter().rev().map(|key| key.to_string().parse().unwrap()).collect();
+        parsed.sort();
+        assert_eq!(parsed, keys);
+    }
+
+    #[test]
+    fn test_recover_public_key() {
+        use sha2::Digest;
+        let msg: [u8; 32] = sha2::Sha256::digest(b"123").into();
+
+        let sk = SecretKey::from_random(KeyType::SECP256K1);
+        let signature = sk.sign(&msg);
+        assert_eq!(signature.recover_public_key(msg).unwrap(), sk.public_key());
+        let other: [u8; 32] = sha2::Sha256::digest(b"456").into();
+        assert_ne!(signature.recover_public_key(other).unwrap(), sk.public_key());
+
+        let Signature::SECP256K1(mut invalid) = signature else { unreachable!() };
+        invalid.0[64] = 4;
+        assert!(matches!(
+            Signature::SECP256K1(invalid).recover_public_key(msg),
+            Err(crate::errors::RecoverError::InvalidSignature { .. })
+        ));
+
+        let signature = SecretKey::from_seed(KeyType::ED25519, "test").sign(&msg);
+        assert!(matches!(
+            signature.recover_public_key(msg),
+            Err(crate::errors::RecoverError::NotRecoverable { key_type: KeyType::ED25519 })
+        ));
+    }
+
+    #[test]
+    fn test_sign_with_context() {
+        for key_type in [KeyType::ED25519, KeyType::SECP256K1, KeyType::RSA2048, KeyType::P256] {
+            let sk = SecretKey::from_seed(key_type, "test");
+            let pk = sk.public_key();
+            let signature = sk.sign_with_context(b"transfer", b"123").unwrap();
+            assert!(signature.verify_with_context(b"transfer", b"123", &pk), "{key_type}");
+            assert!(!signature.verify_with_context(b"stake", b"123", &pk), "{key_type}");
+            assert!(!signature.verify_with_context(b"transfer", b"456", &pk), "{key_type}");
+            // The length prefix keeps the context and data apart.
+            assert!(!signature.verify_with_context(b"transfer1", b"23", &pk), "{key_type}");
+        }
+    }
+
+    #[test]
+    fn test_newtype_serialization() {
+        fn check<T>(value: T)
+        where
+            T: BorshSerialize
+                + BorshDeserialize
+                + serde::Serialize
+                + serde::de::DeserializeOwned
+                + PartialEq
+                + Debug
+                + Display,
+        {
+            let bytes = borsh::to_vec(&value).unwrap();
+            assert_eq!(T::try_from_slice(&bytes).unwrap(), value);
+            let json = serde_json::to_string(&value).unwrap();
+            assert!(!json.contains(':'), "{json}");
+            assert_eq!(serde_json::from_str::<T>(&json).unwrap(), value);
+            let binary = bincode::serialize(&value).unwrap();
+            assert!(binary.ends_with(&bytes));
+            assert_eq!(bincode::deserialize::<T>(&binary).unwrap(), value);
+            let legacy = bincode::serialize(&value.to_string()).unwrap();
+            assert_eq!(bincode::deserialize::<T>(&legacy).unwrap(), value);
+        }
+
+        use sha2::Digest;
+        let data = sha2::Sha256::digest(b"123").to_vec();
+
+        let sk = SecretKey::from_seed(KeyType::ED25519, "test");
+        check(sk.public_key().unwrap_as_ed25519().clone());
+
+        let sk = SecretKey::from_seed(KeyType::SECP256K1, "test");
+        check(sk.public_key().unwrap_as_secp256k1().clone());
+        let Signature::SECP256K1(signature) = sk.sign(&data) else { unreachable!() };
+        check(signature);
+
+        let sk = SecretKey::from_seed(KeyType::RSA2048, "test");
+        check(sk.public_key().unwrap_as_rsa2048().clone());
+        let Signature::RSA(signature) = sk.sign(&data) else { unreachable!() };
+        check(signature);
+
+        let sk = SecretKey::from_seed(KeyType::P256, "test");
+        check(sk.public_key().unwrap_as_p256().clone());
+        let Signature::P256(signature) = sk.sign(&data) else { unreachable!() };
+        check(signature);
+
+        let bytes = borsh::to_vec(sk.public_key().unwrap_as_p256()).unwrap();
+        assert_eq!(bytes, sk.public_key().key_data());
+        assert!(serde_json::from_str::<P256PublicKey>("\"abc\"").is_err());
+        assert!(P256PublicKey::try_from_slice(&[1; PUBLIC_KEY_P256_LENGTH]).is_err());
+    }
+
+    #[test]
+    fn test_verify_and_recover() {
+        use sha2::Digest;
+        let data = sha2::Sha256::digest(b"123").to_vec();
+
+        let sk = SecretKey::from_seed(KeyType::SECP256K1, "test");
+        let signature = sk.sign(&data);
+        let signer = signature.verify_and_recover(&data).unwrap();
+        assert_eq!(signer, sk.public_key());
+        assert!(signature.verify(&data, &signer));
+        assert_eq!(signature.verify_and_recover(&data[..31]), None);
+
+        let sk = SecretKey::from_seed(KeyType::ED25519, "test");
+        assert_eq!(sk.sign(&data).verify_and_recover(&data), None);
+    }
+
+    #[test]
+    fn test_key_type_lengths() {
+        use sha2::Digest;
+        let data = sha2::Sha256::digest(b"123").to_vec();
+        for key_type in [KeyType::ED25519, KeyType::SECP256K1, KeyType::RSA2048, KeyType::P256] {
+            let sk = SecretKey::from_seed(key_type, "test");
+            let pk = sk.public_key();
+            assert_eq!(pk.key_data().len(), key_type.public_key_len(), "{key_type}");
+            assert_eq!(pk.len(), key_type.public_key_len().saturating_add(1), "{key_type}");
+            let signature = borsh::to_vec(&sk.sign(&data)).unwrap();
+            assert_eq!(signature.len().checked_sub(1), key_type.signature_len(), "{key_type}");
+        }
+    }
+
+    #[test]
+    fn test_try_from_tagged() {
+        use crate::errors::ParseSignatureError;
+        use sha2::Digest;
+        let data = sha2::Sha256::digest(b"123").to_vec();
+
+        let signatures: Vec<Signature> =
+            [KeyType::ED25519, KeyType::SECP256K1, KeyType::RSA2048, KeyType::P256]
+                .into_iter()
+                .map(|key_type| SecretKey::from_seed(key_type, "test").sign(&data))
+                .collect();
+
+        for signature in signatures {
+            let mut tagged = borsh::to_vec(&signature).unwrap();
+            assert_eq!(Signature::try_from_tagged(&tagged).unwrap(), signature);
+            assert_eq!(Signature::try_from(tagged.as_slice()).unwrap(), signature);
+
+            let truncated = &tagged[..tagged.len().saturating_sub(1)];
+            assert!(matches!(
+                Signature::try_from_tagged(truncated),
+                Err(ParseSignatureError::InvalidLength { .. })
+            ));
+            tagged.push(0);
+            assert!(matches!(
+                Signature::try_from_tagged(&tagged),
+                Err(ParseSignatureError::InvalidLength { .. })
+            ));
+        }
+
+        assert!(matches!(
+            Signature::try_from_tagged(&[]),
+            Err(ParseSignatureError::InvalidLength { expected_length: 1, received_length: 0 })
+        ));
+        assert!(matches!(
+            Signature::try_from_tagged(&[42; 65]),
+            Err(ParseSignatureError::UnknownKeyType { .. })
+        ));
+    }
+
+    #[test]
+    fn test_too_long_data_length() {
+        use crate::errors::{ParseKeyError, ParseSignatureError};
+        let encoded = bs58::encode([1u8; 40]).into_string();
+        assert!(matches!(
+            PublicKey::from_str(&format!("ed25519:{encoded}")),
+            Err(ParseKeyError::InvalidLength { expected_length: 32, received_length: 40 })
+        ));
+        assert!(matches!(
+            Signature::from_str(&format!("ed25519:{encoded}")),
+            Err(ParseSignatureError::InvalidLength { expected_length: 64, received_length: 40 })
+        ));
+        let encoded = bs58::encode([1u8; 100]).into_string();
+        assert!(matches!(
+            Signature::from_str(&format!("ed25519:{encoded}")),
+            Err(ParseSignatureError::InvalidLength { expected_length: 64, received_length: 100 })
+        ));
+        let encoded = bs58::encode([1u8; 3000]).into_string();
+        assert!(matches!(
+            SecretKey::from_str(&format!("rsa2048:{encoded}")),
+            Err(ParseKeyError::InvalidLength { expected_length: 2048, received_length: 3000 })
+        ));
+    }
+
+    #[test]
+    fn test_from_parts_ed25519_high_bits() {
+        let mut data = [1u8; ed25519_dalek::SIGNATURE_LENGTH];
+        assert!(Signature::from_parts(KeyType::ED25519, &data).is_ok());
+        data[ed25519_dalek::SIGNATURE_LENGTH - 1] = 0b1000_0000;
+        assert!(matches!(
+            Signature::from_parts(KeyType::ED25519, &data),
+            Err(crate::errors::ParseSignatureError::InvalidData { .. })
+        ));
+        let mut tagged = vec![KeyType::ED25519 as u8];
+        tagged.extend_from_slice(&data);
+        assert!(Signature::try_from_slice(&tagged).is_err());
+        let value = format!("ed25519:{}", bs58::encode(&data).into_string());
+        assert!(matches!(
+            Signature::from_str(&value),
+            Err(crate::errors::ParseSignatureError::InvalidData { .. })
+        ));
+        assert!(serde_json::from_str::<Signature>(&format!("\"{value}\"")).is_err());
+    }
+
+    #[test]
+    fn test_schema_pattern() {
+        let key_pattern = regex::Regex::new(PublicKey::schema_pattern()).unwrap();
+        let secret_key_pattern = regex::Regex::new(SecretKey::schema_pattern()).unwrap();
+        let signature_pattern = regex::Regex::new(Signature::schema_pattern()).unwrap();
+        let data = [7u8; 32];
+        for key_type in [KeyType::ED25519, KeyType::SECP256K1, KeyType::RSA2048, KeyType::P256] {
+            let sk = SecretKey::from_seed(key_type, "test");
+            assert!(key_pattern.is_match(&sk.public_key().to_string()), "{key_type}");
+            assert!(secret_key_pattern.is_match(&sk.to_string()), "{key_type}");
+            assert!(signature_pattern.is_match(&sk.sign(&data).to_string()), "{key_type}");
+        }
+
+        for invalid in [
+            "",
+            "ed25519:",
+            "DcA2MzgpJbrUATQLLceocVckhhAqrkingax4oJ9kZ847",
+            "ed25519:DcA2MzgpJbrUATQLLceocVckhhAqrkingax4oJ9kZ84l",
+            "ed448:DcA2MzgpJbrUATQLLceocVckhhAqrkingax4oJ9kZ847",
+            " ed25519:DcA2MzgpJbrUATQLLceocVckhhAqrkingax4oJ9kZ847",
+        ] {
+            assert!(!key_pattern.is_match(invalid), "{invalid}");
+            assert!(!signature_pattern.is_match(invalid), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_ed25519_secret_key_mismatched_halves() {
+        let SecretKey::ED25519(mut keypair) = SecretKey::from_seed(KeyType::ED25519, "test") else {
+            unreachable!()
+        };
+        let other = PublicKey::from_seed(KeyType::ED25519, "other");
+        keypair.0[ed25519_dalek::SECRET_KEY_LENGTH..].copy_from_slice(other.key_data());
+        let encoded = format!("ed25519:{}", bs58::encode(&keypair.0).into_string());
+        assert!(matches!(
+            SecretKey::from_str(&encoded),
+            Err(crate::errors::ParseKeyError::InvalidData { .. })
+        ));
+    }
+
+    #[test]
+    fn test_compact_encoding() {
+        let data = [7u8; 32];
+        let signatures: Vec<Signature> =
+            [KeyType::ED25519, KeyType::SECP256K1, KeyType::RSA2048, KeyType::P256]
+                .into_iter()
+                .map(|key_type| SecretKey::from_seed(key_type, "test").sign(&data))
+                .collect();
+
+        for (signature, tag) in signatures.iter().zip([0x00, 0x01, 0x02, 0x03]) {
+            let encoded = signature.encode_compact();
+            assert_eq!(encoded, borsh::to_vec(signature).unwrap());
+            assert_eq!(encoded[0], tag);
+            assert_eq!(Signature::decode_compact(&encoded).unwrap(), *signature);
+        }
+    }
+
+    #[test]
+    fn test_verify_any() {
+        let data = b"123";
+        let keys: Vec<PublicKey> =
+            ["a", "b", "c"].map(|seed| PublicKey::from_seed(KeyType::ED25519, seed)).to_vec();
+        let signature = SecretKey::from_seed(KeyType::ED25519, "b").sign(data);
+        assert_eq!(signature.verify_any(data, &keys), Some(1));
+        assert_eq!(signature.verify_any(b"456", &keys), None);
+        assert_eq!(signature.verify_any(data, &[]), None);
+    }
+
+    #[test]
+    fn test_verify_identify() {
+        let data = [1u8; 32];
+        let keys: Vec<PublicKey> = [KeyType::ED25519, KeyType::SECP256K1, KeyType::P256]
+            .map(|key_type| PublicKey::from_seed(key_type, "test"))
+            .to_vec();
+        let signature = SecretKey::from_seed(KeyType::SECP256K1, "test").sign(&data);
+        assert!(matches!(signature.verify_identify(&data, &keys), Some((1, KeyType::SECP256K1))));
+        let signature = SecretKey::from_seed(KeyType::P256, "test").sign(&data);
+        assert!(matches!(signature.verify_identify(&data, &keys), Some((2, KeyType::P256))));
+        assert!(signature.verify_identify(&[2u8; 32], &keys).is_none());
+        assert!(signature.verify_identify(&data, &keys[..2]).is_none());
+    }
+
+    #[test]
+    fn test_validate_structure() {
+        use crate::errors::ValidationError;
+        let data = [7u8; 32];
+        for key_type in [KeyType::ED25519, KeyType::SECP256K1, KeyType::RSA2048, KeyType::P256] {
+            let signature = SecretKey::from_seed(key_type, "test").sign(&data);
+            assert!(signature.validate_structure().is_ok(), "{key_type}");
+        }
+
+        let Signature::SECP256K1(signature) =
+            SecretKey::from_seed(KeyType::SECP256K1, "test").sign(&data)
+        else {
+            unreachable!()
+        };
+        let mut bad_recovery_id = signature.clone();
+        bad_recovery_id.0[64] = 4;
+        assert!(matches!(
+            Signature::SECP256K1(bad_recovery_id).validate_structure(),
+            Err(ValidationError::InvalidRecoveryId { recovery_id: 4 })
+        ));
+        let mut high_s = signature.clone();
+        high_s.0[32..64].copy_from_slice(&[0xff; 32]);
+        assert!(matches!(
+            Signature::SECP256K1(high_s).validate_structure(),
+            Err(ValidationError::InvalidSignatureValues)
+        ));
+        let mut big_r = signature;
+        big_r.0[..32].copy_from_slice(&[0xff; 32]);
+        assert!(matches!(
+            Signature::SECP256K1(big_r).validate_structure(),
+            Err(ValidationError::InvalidSignatureValues)
+        ));
+
+        assert!(matches!(
+            Signature::P256(P256Signature([0; 64])).validate_structure(),
+            Err(ValidationError::InvalidSignatureValues)
+        ));
+
+        let mut bytes = [0u8; ed25519_dalek::SIGNATURE_LENGTH];
+        bytes[ed25519_dalek::SIGNATURE_LENGTH - 1] = 0b1110_0000;
+        let high_bits = Signature::ED25519(ed25519_dalek::Signature::from_bytes(&bytes));
+        assert!(matches!(high_bits.validate_structure(), Err(ValidationError::Ed25519HighBits)));
+    }
+
+    #[test]
+    fn test_secret_key_from_parts() {
+        use crate::errors::ParseKeyError;
+        for key_type in [KeyType::ED25519, KeyType::SECP256K1, KeyType::RSA2048, KeyType::P256] {
+            let sk = SecretKey::from_seed(key_type, "test");
+            let data = sk.to_secret_bytes();
+            assert_eq!(SecretKey::from_parts(key_type, &data).unwrap(), sk, "{key_type}");
+            let value = sk.to_string();
+            let (_, encoded) = split_key_type_data(&value).unwrap();
+            assert_eq!(bs58::encode(data.as_slice()).into_string(), encoded, "{key_type}");
+            assert!(SecretKey::from_parts(key_type, &data[1..]).is_err(), "{key_type}");
+        }
+
+        let SecretKey::ED25519(sk) = SecretKey::from_seed(KeyType::ED25519, "test") else {
+            unreachable!()
+        };
+        assert_eq!(
+            SecretKey::from_parts(KeyType::ED25519, &sk.seed()).unwrap(),
+            SecretKey::ED25519(sk.clone())
+        );
+        let mut keypair = sk.0;
+        keypair[63] ^= 1;
+        assert!(matches!(
+            SecretKey::from_parts(KeyType::ED25519, &keypair),
+            Err(ParseKeyError::InvalidData { .. })
+        ));
+        assert!(matches!(
+            SecretKey::from_parts(KeyType::SECP256K1, &[0; 31]),
+            Err(ParseKeyError::InvalidLength { expected_length: 32, received_length: 31 })
+        ));
+        assert!(matches!(
+            SecretKey::from_parts(KeyType::SECP256K1, &[0; 32]),
+            Err(ParseKeyError::InvalidData { .. })
+        ));
+        assert!(matches!(
+            SecretKey::from_parts(KeyType::P256, &[0; 33]),
+            Err(ParseKeyError::InvalidLength { expected_length: 32, received_length: 33 })
+        ));
+        assert!(SecretKey::from_parts(KeyType::RSA2048, &[0; 32]).is_err());
+
+        let seed_only = format!("ed25519:{}", bs58::encode(sk.seed()).into_string());
+        assert!(matches!(
+            SecretKey::from_str(&seed_only),
+            Err(ParseKeyError::InvalidLength { expected_length: 64, received_length: 32 })
+        ));
+        assert!(matches!(
+            SecretKey::from_str("secp256k1:1111111111111111111111111111111111"),
+            Err(ParseKeyError::InvalidLength { expected_length: 32, received_length: 34 })
+        ));
+    }
+
+    #[test]
+    fn test_base58check() {
+        use crate::errors::{ParseKeyError, ParseSignatureError};
+
+        fn corrupt(value: &str) -> String {
+            let mut value = value.to_string();
+            let last = if value.pop() == Some('2') { '3' } else { '2' };
+            value.push(last);
+            value
+        }
+
+        for key_type in [KeyType::ED25519, KeyType::SECP256K1, KeyType::RSA2048, KeyType::P256] {
+            let sk = SecretKey::from_seed(key_type, "test");
+            let pk = sk.public_key();
+            let signature = sk.sign(&[7u8; 32]);
+
+            let encoded = pk.to_base58check();
+            assert!(encoded.starts_with(&format!("{key_type}:")), "{encoded}");
+            assert_ne!(encoded, pk.to_string());
+            assert_eq!(PublicKey::from_base58check(&encoded).unwrap(), pk);
+            assert!(matches!(
+                PublicKey::from_base58check(&corrupt(&encoded)),
+                Err(ParseKeyError::ChecksumMismatch)
+            ));
+
+            let encoded = sk.to_base58check();
+            assert_eq!(SecretKey::from_base58check(&encoded).unwrap(), sk);
+            assert!(matches!(
+                SecretKey::from_base58check(&corrupt(&encoded)),
+                Err(ParseKeyError::ChecksumMismatch)
+            ));
+
+            let encoded = signature.to_base58check();
+            assert_eq!(Signature::from_base58check(&encoded).unwrap(), signature);
+            assert!(matches!(
+                Signature::from_base58check(&corrupt(&encoded)),
+                Err(ParseSignatureError::ChecksumMismatch)
+            ));
+        }
+
+        let pk = PublicKey::from_seed(KeyType::ED25519, "test");
+        let encoded = pk.to_base58check();
+        let (_, data) = encoded.split_once(':').unwrap();
+        assert_eq!(PublicKey::from_base58check(data).unwrap(), pk);
+        // Plain base58 is rejected since the last four bytes aren't a checksum.
+        assert!(PublicKey::from_base58check(&pk.to_string()).is_err());
+    }
+
+    #[test]
+    fn test_try_sign_message_length() {
+        let sk = SecretKey::from_seed(KeyType::SECP256K1, "test");
+        assert!(matches!(
+            sk.try_sign(b"123"),
+            Err(crate::errors::SigningError::MessageLength { expected: 32, received: 3 })
+        ));
+        let data = [7u8; 32];
+        assert!(sk.try_sign(&data).unwrap().verify(&data, &sk.public_key()));
+
+        let sk = SecretKey::from_seed(KeyType::ED25519, "test");
+        assert!(sk.try_sign(b"123").unwrap().verify(b"123", &sk.public_key()));
+    }
+
+    #[test]
+    fn test_ethereum_address() {
+        let key = hex::decode("4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318")
+            .unwrap();
+        let sk = SecretKey::SECP256K1(secp256k1::SecretKey::from_slice(&key).unwrap());
+        assert_eq!(
+            hex::encode(sk.public_key().to_address(AddressScheme::Ethereum).unwrap()),
+            "2c7536e3605d9c16a7a3d7b1898e529396a65c23"
+        );
+        assert_eq!(
+            PublicKey::from_seed(KeyType::ED25519, "test").to_address(AddressScheme::Ethereum),
+            None
+        );
+    }
+
+    #[test]
+    fn test_from_array() {
+        let data = [7u8; 32];
+        let sk = SecretKey::from_seed(KeyType::SECP256K1, "test");
+        let Signature::SECP256K1(signature) = sk.sign(&data) else { unreachable!() };
+        let signature = Secp256K1Signature::from_array(signature.into());
+        let public_key =
+            Secp256K1PublicKey::from_array(sk.public_key().key_data().try_into().unwrap());
+        assert!(Signature::SECP256K1(signature).verify(&data, &PublicKey::SECP256K1(public_key)));
+
+        const ED25519_KEY: ED25519PublicKey = ED25519PublicKey::from_array([0; 32]);
+        assert!(PublicKey::ED25519(ED25519_KEY).is_zero());
+        assert_eq!(P256Signature::from_array([1; 64]), P256Signature([1; 64]));
+        let p256 = PublicKey::from_seed(KeyType::P256, "test");
+        let data = p256.key_data().try_into().unwrap();
+        assert_eq!(PublicKey::P256(P256PublicKey::from_array(data).unwrap()), p256);
+        assert!(P256PublicKey::from_array([0; 64]).is_err());
+    }
+
+    #[test]
+    fn test_verify_digest() {
+        use sha2::Digest;
+        let data = b"123";
+        let digest: [u8; 32] = sha2::Sha256::digest(data).into();
+        for key_type in [KeyType::ED25519, KeyType::SECP256K1, KeyType::RSA2048] {
+            let sk = SecretKey::from_seed(key_type, "test");
+            let signature = sk.sign(&digest);
+            assert!(signature.verify_digest(&digest, &sk.public_key()), "{key_type}");
+            assert!(!signature.verify_digest(&[0; 32], &sk.public_key()), "{key_type}");
+        }
+
+        let sk = SecretKey::from_seed(KeyType::P256, "test");
+        let signature = sk.sign(data);
+        assert!(signature.verify_digest(&digest, &sk.public_key()));
+        assert!(!signature.verify(&digest, &sk.public_key()));
+        assert!(!signature.verify_digest(&[0; 32], &sk.public_key()));
+    }
+
+    #[test]
+    fn test_ed25519_seed() {
+        let sk = SecretKey::from_seed(KeyType::ED25519, "test");
+        let SecretKey::ED25519(full) = &sk else { unreachable!() };
+        let restored = ED25519SecretKey::from_seed_bytes(&full.seed());
+        assert_eq!(restored.0, full.0);
+        let restored = SecretKey::ED25519(restored);
+        assert_eq!(restored.public_key(), sk.public_key());
+        assert_eq!(restored.sign(b"123"), sk.sign(b"123"));
+    }
+
+    #[test]
+    fn test_parse_with_whitespace() {
+        let sk = SecretKey::from_seed(KeyType::ED25519, "test");
+        let public_key = sk.public_key();
+        let signature = sk.sign(b"123");
+        let key_data = public_key.to_string().split_once(':').unwrap().1.to_string();
+        let sig_data = signature.to_string().split_once(':').unwrap().1.to_string();
+        for format in [" ed25519 : {} ", "ED25519:{}", "\tEd25519 :{}\n", "  {}  "] {
+            let key = format.replace("{}", &key_data);
+            assert_eq!(PublicKey::from_str(&key).unwrap(), public_key, "{key:?}");
+            let sig = format.replace("{}", &sig_data);
+            assert_eq!(Signature::from_str(&sig).unwrap(), signature, "{sig:?}");
+        }
+        let (head, tail) = key_data.split_at(10);
+        assert!(PublicKey::from_str(&format!("ed25519:{head} {tail}")).is_err());
+        assert!(PublicKey::from_str(&format!("ed 25519:{key_data}")).is_err());
+    }
+}
@@ -0,0 +1,243 @@
+//! NIST P-256 (secp256r1) ECDSA keys and signatures.
+
+use super::{decode_bs58, deserialize_str_or_bytes, serialize_str_or_bytes, Bs58, Signature};
+use borsh::{BorshDeserialize, BorshSerialize};
+use std::fmt::{Debug, Display, Formatter};
+use std::io::{Error, ErrorKind, Read};
+use std::str::FromStr;
+
+pub(crate) const PUBLIC_KEY_P256_LENGTH: usize = 64;
+
+/// Uncompressed `x ‖ y` coordinates of the P-256 generator point.  Used as
+/// the placeholder [`PublicKey::empty`](crate::PublicKey::empty) key since
+/// all-zero bytes aren't a point on the curve.  Signatures never verify
+/// against it.
+pub(super) const P256_GENERATOR: [u8; PUBLIC_KEY_P256_LENGTH] = [
+    0x6b, 0x17, 0xd1, 0xf2, 0xe1, 0x2c, 0x42, 0x47, 0xf8, 0xbc, 0xe6, 0xe5, 0x63, 0xa4, 0x40, 0xf2,
+    0x77, 0x03, 0x7d, 0x81, 0x2d, 0xeb, 0x33, 0xa0, 0xf4, 0xa1, 0x39, 0x45, 0xd8, 0x98, 0xc2, 0x96,
+    0x4f, 0xe3, 0x42, 0xe2, 0xfe, 0x1a, 0x7f, 0x9b, 0x8e, 0xe7, 0xeb, 0x4a, 0x7c, 0x0f, 0x9e, 0x16,
+    0x2b, 0xce, 0x33, 0x57, 0x6b, 0x31, 0x5e, 0xce, 0xcb, 0xb6, 0x40, 0x68, 0x37, 0xbf, 0x51, 0xf5,
+];
+
+/// P-256 public key.  It's always a point on the curve, all constructors check
+/// that.
+#[derive(Clone, Eq, Ord, PartialEq, PartialOrd, derive_more::AsRef, BorshSerialize)]
+#[as_ref(forward)]
+pub struct P256PublicKey(pub(super) [u8; PUBLIC_KEY_P256_LENGTH]);
+
+/// Generates keys from random seeds since random bytes are rarely a point on
+/// the curve.
+#[cfg(test)]
+impl bolero::TypeGenerator for P256PublicKey {
+    fn generate<D: bolero::Driver>(driver: &mut D) -> Option<Self> {
+        let seed = <[u8; 32]>::generate(driver)?;
+        let secret_key = crate::SecretKey::from_seed(crate::KeyType::P256, &hex::encode(seed));
+        Some(secret_key.public_key().unwrap_as_p256().clone())
+    }
+}
+
+impl BorshDeserialize for P256PublicKey {
+    fn deserialize_reader<R: Read>(rd: &mut R) -> std::io::Result<Self> {
+        Self::from_point(BorshDeserialize::deserialize_reader(rd)?)
+            .map_err(|err| Error::new(ErrorKind::InvalidData, err.to_string()))
+    }
+}
+
+impl TryFrom<&[u8]> for P256PublicKey {
+    type Error = crate::errors::ParseKeyError;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        let data = data.try_into().map_err(|_| Self::Error::InvalidLength {
+            expected_length: PUBLIC_KEY_P256_LENGTH,
+            received_length: data.len(),
+        })?;
+        Self::from_point(data)
+    }
+}
+
+impl P256PublicKey {
+    /// Constructs the key from the uncompressed `x ‖ y` coordinates.  Unlike
+    /// `from_array` of other key types this fails if the coordinates aren't
+    /// a point on the curve.
+    pub fn from_array(
+        data: [u8; PUBLIC_KEY_P256_LENGTH],
+    ) -> Result<Self, crate::errors::ParseKeyError> {
+        Self::from_point(data)
+    }
+
+    /// Constructs the key from the uncompressed `x ‖ y` coordinates, checking
+    /// that they are a point on the curve.
+    pub(super) fn from_point(
+        data: [u8; PUBLIC_KEY_P256_LENGTH],
+    ) -> Result<Self, crate::errors::ParseKeyError> {
+        p256::PublicKey::from_sec1_bytes(&Self::to_sec1(&data)).map_err(|_| {
+            crate::errors::ParseKeyError::InvalidData {
+                error_message: "not a point on the P-256 curve".to_string(),
+            }
+        })?;
+        Ok(Self(data))
+    }
+
+    fn to_sec1(data: &[u8; PUBLIC_KEY_P256_LENGTH]) -> [u8; PUBLIC_KEY_P256_LENGTH + 1] {
+        let mut sec1 = [4u8; PUBLIC_KEY_P256_LENGTH + 1];
+        sec1[1..].copy_from_slice(data);
+        sec1
+    }
+
+    /// Returns the key for verifying signatures.  Fails for the
+    /// [`P256_GENERATOR`] placeholder.
+    pub(super) fn verifying_key(&self) -> Result<p256::ecdsa::VerifyingKey, p256::ecdsa::Error> {
+        if self.0 == P256_GENERATOR {
+            return Err(p256::ecdsa::Error::new());
+        }
+        p256::ecdsa::VerifyingKey::from_sec1_bytes(&Self::to_sec1(&self.0))
+    }
+}
+
+impl std::fmt::Debug for P256PublicKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        Display::fmt(&Bs58(&self.0), f)
+    }
+}
+
+impl_bs58_serde!(P256PublicKey, PUBLIC_KEY_P256_LENGTH, crate::errors::ParseKeyError);
+
+pub(super) const P256_SECRET_KEY_LENGTH: usize = 32;
+pub(crate) const P256_SIGNATURE_LENGTH: usize = 64;
+
+/// Fixed-size `r || s` encoding of a P-256 ECDSA signature.
+#[derive(
+    Clone,
+    Eq,
+    PartialEq,
+    Hash,
+    derive_more::From,
+    derive_more::Into,
+    BorshSerialize,
+    BorshDeserialize,
+)]
+pub struct P256Signature(pub(super) [u8; P256_SIGNATURE_LENGTH]);
+
+impl TryFrom<&[u8]> for P256Signature {
+    type Error = crate::errors::ParseSignatureError;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self(data.try_into().map_err(|_| Self::Error::InvalidLength {
+            expected_length: P256_SIGNATURE_LENGTH,
+            received_length: data.len(),
+        })?))
+    }
+}
+
+impl Debug for P256Signature {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        Display::fmt(&Bs58(&self.0), f)
+    }
+}
+
+impl_bs58_serde!(P256Signature, P256_SIGNATURE_LENGTH, crate::errors::ParseSignatureError);
+impl_from_array!(P256Signature, P256_SIGNATURE_LENGTH);
+
+/// Wraps a P-256 signature normalizing it to low `s` since, as with
+/// secp256k1, signatures with high `s` don't verify.
+pub(super) fn p256_signature(signature: p256::ecdsa::Signature) -> Signature {
+    let signature = signature.normalize_s().unwrap_or(signature);
+    let mut buf = [0; P256_SIGNATURE_LENGTH];
+    buf.copy_from_slice(&signature.to_bytes());
+    Signature::P256(P256Signature(buf))
+}
+
+/// Parses a P-256 signature rejecting high `s` values, which would otherwise
+/// make signatures malleable.
+pub(super) fn parse_p256_signature(
+    data: &[u8; P256_SIGNATURE_LENGTH],
+) -> Option<p256::ecdsa::Signature> {
+    let signature = p256::ecdsa::Signature::from_slice(data).ok()?;
+    signature.normalize_s().is_none().then_some(signature)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{KeyType, PublicKey, SecretKey};
+
+    #[test]
+    fn test_json_serialize_p256() {
+        let data = b"123";
+
+        let sk = SecretKey::from_seed(KeyType::P256, "test");
+        let pk = sk.public_key();
+        assert_eq!(pk, PublicKey::from_seed(KeyType::P256, "test"));
+        let pk_str = serde_json::to_string(&pk).unwrap();
+        assert!(pk_str.starts_with("\"p256:"));
+        assert_eq!(pk, serde_json::from_str(&pk_str).unwrap());
+        let pk2: PublicKey = pk.to_string().parse().unwrap();
+        assert_eq!(pk, pk2);
+
+        let sk_str = serde_json::to_string(&sk).unwrap();
+        assert!(sk_str.starts_with("\"p256:"));
+        assert_eq!(sk, serde_json::from_str(&sk_str).unwrap());
+
+        let signature = sk.sign(data);
+        assert!(signature.verify(data, &pk));
+        assert!(!signature.verify(b"456", &pk));
+        assert!(!signature.verify(data, &PublicKey::from_seed(KeyType::P256, "other")));
+        let signature_str = serde_json::to_string(&signature).unwrap();
+        assert!(signature_str.starts_with("\"p256:"));
+        assert_eq!(signature, serde_json::from_str(&signature_str).unwrap());
+        let signature2: Signature = signature.to_string().parse().unwrap();
+        assert_eq!(signature, signature2);
+    }
+
+    #[test]
+    fn test_p256_validation() {
+        let sk = SecretKey::from_seed(KeyType::P256, "test");
+        let pk = sk.public_key();
+        let mut off_curve = pk.key_data().to_vec();
+        off_curve[63] ^= 1;
+        assert!(P256PublicKey::try_from(pk.key_data()).is_ok());
+        assert!(matches!(
+            P256PublicKey::try_from(off_curve.as_slice()),
+            Err(crate::errors::ParseKeyError::InvalidData { .. })
+        ));
+        let value = format!("p256:{}", bs58::encode(&off_curve).into_string());
+        assert!(PublicKey::from_str(&value).is_err());
+        let mut bytes = borsh::to_vec(&pk).unwrap();
+        assert_eq!(PublicKey::try_from_slice(&bytes).unwrap(), pk);
+        bytes[64] ^= 1;
+        assert!(PublicKey::try_from_slice(&bytes).is_err());
+
+        // Signing normalizes `s`, so build the high-S counterpart (r, n - s)
+        // of a signature by hand; it must not verify.
+        for data in [b"123", b"456", b"789"] {
+            let signature = sk.sign(data);
+            let Signature::P256(low_s) = &signature else { unreachable!() };
+            let parsed = p256::ecdsa::Signature::from_slice(&low_s.0).unwrap();
+            assert!(parsed.normalize_s().is_none());
+            let high_s = p256::ecdsa::Signature::from_scalars(parsed.r(), -parsed.s()).unwrap();
+            let mut buf = [0; P256_SIGNATURE_LENGTH];
+            buf.copy_from_slice(&high_s.to_bytes());
+            let high_s = Signature::P256(P256Signature(buf));
+            assert!(signature.verify(data, &pk));
+            assert!(matches!(
+                high_s.verify_detailed(data, &pk),
+                Err(crate::errors::VerifyFailure::MalformedSignature)
+            ));
+            assert!(high_s.validate_structure().is_err());
+        }
+    }
+
+    #[test]
+    fn test_p256_empty() {
+        use p256::elliptic_curve::sec1::ToEncodedPoint;
+
+        let generator = p256::AffinePoint::GENERATOR.to_encoded_point(false);
+        assert_eq!(&generator.as_bytes()[1..], P256_GENERATOR.as_slice());
+
+        let empty = PublicKey::empty(KeyType::P256);
+        assert_eq!(PublicKey::from_str(&empty.to_string()).unwrap(), empty);
+        assert_eq!(PublicKey::try_from_slice(&borsh::to_vec(&empty).unwrap()).unwrap(), empty);
+        let signature = SecretKey::from_seed(KeyType::P256, "test").sign(b"123");
+        assert!(!signature.verify(b"123", &empty));
+    }
+}
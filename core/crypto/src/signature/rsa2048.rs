@@ -0,0 +1,441 @@
+//! RSA-2048 keys and signatures.
+
+use super::{
+    decode_bs58, deserialize_str_or_bytes, serialize_str_or_bytes, Bs58, KeyType, PublicKey,
+    SecretKey, Signature,
+};
+use borsh::{BorshDeserialize, BorshSerialize};
+use rsa::pkcs8::DecodePublicKey;
+use rsa::{Pkcs1v15Sign, Pss};
+use secp256k1::rand::rngs::OsRng;
+use std::fmt::{Debug, Display, Formatter};
+use std::str::FromStr;
+
+pub(crate) const RAW_PUBLIC_KEY_RSA_2048_LENGTH: usize = 294;
+#[derive(
+    Clone,
+    Eq,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    derive_more::AsRef,
+    derive_more::From,
+    BorshSerialize,
+    BorshDeserialize,
+)]
+#[cfg_attr(test, derive(bolero::TypeGenerator))]
+#[as_ref(forward)]
+pub struct Rsa2048PublicKey(pub(super) [u8; RAW_PUBLIC_KEY_RSA_2048_LENGTH]);
+
+impl TryFrom<&[u8]> for crate::Rsa2048PublicKey {
+    type Error = crate::errors::ParseKeyError;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        data.try_into().map(Self).map_err(|_| Self::Error::InvalidLength {
+            expected_length: RAW_PUBLIC_KEY_RSA_2048_LENGTH,
+            received_length: data.len(),
+        })
+    }
+}
+
+impl std::fmt::Debug for crate::Rsa2048PublicKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        Display::fmt(&Bs58(&self.0), f)
+    }
+}
+
+impl_bs58_serde!(Rsa2048PublicKey, RAW_PUBLIC_KEY_RSA_2048_LENGTH, crate::errors::ParseKeyError);
+impl_from_array!(Rsa2048PublicKey, RAW_PUBLIC_KEY_RSA_2048_LENGTH);
+
+pub(crate) const RSA2048_SIGNATURE_LENGTH: usize = 256;
+
+#[derive(
+    Clone,
+    Eq,
+    PartialEq,
+    Hash,
+    derive_more::From,
+    derive_more::Into,
+    BorshSerialize,
+    BorshDeserialize,
+)]
+pub struct Rsa2048Signature(pub(super) [u8; RSA2048_SIGNATURE_LENGTH]);
+
+impl TryFrom<&[u8]> for Rsa2048Signature {
+    type Error = crate::errors::ParseSignatureError;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self(data.try_into().map_err(|_| Self::Error::InvalidLength {
+            expected_length: RSA2048_SIGNATURE_LENGTH,
+            received_length: data.len(),
+        })?))
+    }
+}
+
+impl Debug for Rsa2048Signature {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        Display::fmt(&Bs58(&self.0), f)
+    }
+}
+
+impl_bs58_serde!(Rsa2048Signature, RSA2048_SIGNATURE_LENGTH, crate::errors::ParseSignatureError);
+impl_from_array!(Rsa2048Signature, RSA2048_SIGNATURE_LENGTH);
+
+/// Hash function used by [`RsaScheme::Pss`] both to digest the message and
+/// in MGF1.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, BorshSerialize, BorshDeserialize)]
+pub enum RsaPssHash {
+    Sha256,
+    Sha512,
+}
+
+impl RsaPssHash {
+    /// Returns the digest length of the hash function in bytes.
+    pub fn output_len(&self) -> usize {
+        match self {
+            RsaPssHash::Sha256 => 32,
+            RsaPssHash::Sha512 => 64,
+        }
+    }
+
+    /// Returns the longest salt usable with the hash function and an RSA2048
+    /// key, i.e. the 256-byte encoded message length less the digest length
+    /// and two bytes, see RFC 8017 section 9.1.1.
+    fn max_salt_len(&self) -> usize {
+        match self {
+            RsaPssHash::Sha256 => 222,
+            RsaPssHash::Sha512 => 190,
+        }
+    }
+
+    fn pss(&self, salt_len: u16) -> Pss {
+        let salt_len = usize::from(salt_len);
+        match self {
+            RsaPssHash::Sha256 => Pss::new_with_salt::<sha2::Sha256>(salt_len),
+            RsaPssHash::Sha512 => Pss::new_with_salt::<sha2::Sha512>(salt_len),
+        }
+    }
+}
+
+impl Display for RsaPssHash {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        f.write_str(match self {
+            RsaPssHash::Sha256 => "sha256",
+            RsaPssHash::Sha512 => "sha512",
+        })
+    }
+}
+
+/// Signature scheme used by RSA keys.
+///
+/// The string form is `pkcs1v15` or `pss-<hash>[-<salt length>]`, e.g.
+/// `pss-sha256` or `pss-sha512-20`.  The salt length is omitted when it
+/// equals the digest length.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, BorshSerialize, BorshDeserialize)]
+pub enum RsaScheme {
+    /// PKCS#1 v1.5 over an unprefixed digest.
+    Pkcs1v15,
+    /// PSS with the given hash function, MGF1 using the same hash function and
+    /// a random salt of `salt_len` bytes.
+    Pss { hash: RsaPssHash, salt_len: u16 },
+}
+
+impl RsaScheme {
+    /// PSS with SHA-256 and salt length equal to the digest length.
+    pub const PSS_SHA256: RsaScheme = RsaScheme::Pss { hash: RsaPssHash::Sha256, salt_len: 32 };
+    /// PSS with SHA-512 and salt length equal to the digest length.
+    pub const PSS_SHA512: RsaScheme = RsaScheme::Pss { hash: RsaPssHash::Sha512, salt_len: 64 };
+
+    /// Common schemes RSA signatures can be made and verified with, in order
+    /// of preference.  Useful for negotiating the scheme with a peer.
+    pub const SUPPORTED: [RsaScheme; 3] =
+        [RsaScheme::PSS_SHA256, RsaScheme::PSS_SHA512, RsaScheme::Pkcs1v15];
+}
+
+impl Display for RsaScheme {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            RsaScheme::Pkcs1v15 => f.write_str("pkcs1v15"),
+            RsaScheme::Pss { hash, salt_len } if usize::from(*salt_len) == hash.output_len() => {
+                write!(f, "pss-{hash}")
+            }
+            RsaScheme::Pss { hash, salt_len } => write!(f, "pss-{hash}-{salt_len}"),
+        }
+    }
+}
+
+impl FromStr for RsaScheme {
+    type Err = crate::errors::ParseRsaSchemeError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let lowercase_scheme = value.to_ascii_lowercase();
+        if lowercase_scheme == "pkcs1v15" {
+            return Ok(RsaScheme::Pkcs1v15);
+        }
+        let parse_pss = |params: &str| {
+            let (hash, salt_len) = match params.split_once('-') {
+                Some((hash, salt_len)) => (hash, Some(salt_len)),
+                None => (params, None),
+            };
+            let hash = match hash {
+                "sha256" => RsaPssHash::Sha256,
+                "sha512" => RsaPssHash::Sha512,
+                _ => return None,
+            };
+            let salt_len = match salt_len {
+                Some(salt_len) => salt_len.parse().ok()?,
+                None => u16::try_from(hash.output_len()).ok()?,
+            };
+            Some(RsaScheme::Pss { hash, salt_len })
+        };
+        lowercase_scheme
+            .strip_prefix("pss-")
+            .and_then(parse_pss)
+            .ok_or(Self::Err::UnknownScheme { unknown_scheme: lowercase_scheme.clone() })
+    }
+}
+
+const RSA_SIGNATURE_PREFIX: &str = "rsa2048-";
+
+/// RSA2048 signature along with the scheme it was made with, as returned by
+/// [`SecretKey::sign_rsa`].
+///
+/// This is kept apart from [`Signature`], whose encoding is part of the
+/// protocol and which always uses [`RsaScheme::Pkcs1v15`] for RSA keys.  The
+/// Borsh encoding is the Borsh encoded scheme followed by the signature data,
+/// the string form is `rsa2048-<scheme>:base58`, e.g. `rsa2048-pss-sha256:…`.
+#[derive(Clone, PartialEq, Eq, Hash, BorshSerialize, BorshDeserialize)]
+pub struct RsaSignature {
+    pub scheme: RsaScheme,
+    pub signature: Rsa2048Signature,
+}
+
+impl RsaSignature {
+    /// Verifies the signature of `data` with the RSA public key using the
+    /// recorded scheme.  Returns `false` for public keys of other types.
+    pub fn verify(&self, data: &[u8], public_key: &PublicKey) -> bool {
+        let PublicKey::RSA(public_key) = public_key else { return false };
+        let Ok(pk) = rsa::RsaPublicKey::from_public_key_der(&public_key.0) else { return false };
+        match self.scheme {
+            RsaScheme::Pkcs1v15 => {
+                pk.verify(Pkcs1v15Sign::new_unprefixed(), data, &self.signature.0)
+            }
+            RsaScheme::Pss { hash, salt_len } => {
+                pk.verify(hash.pss(salt_len), data, &self.signature.0)
+            }
+        }
+        .is_ok()
+    }
+
+    /// Converts the signature to the protocol [`Signature`].  Only
+    /// [`RsaScheme::Pkcs1v15`] signatures have such form.
+    pub fn to_signature(&self) -> Option<Signature> {
+        match self.scheme {
+            RsaScheme::Pkcs1v15 => Some(Signature::RSA(self.signature.clone())),
+            RsaScheme::Pss { .. } => None,
+        }
+    }
+}
+
+impl Display for RsaSignature {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}{}:{}", RSA_SIGNATURE_PREFIX, self.scheme, Bs58(&self.signature.0))
+    }
+}
+
+impl Debug for RsaSignature {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        Display::fmt(self, f)
+    }
+}
+
+impl FromStr for RsaSignature {
+    type Err = crate::errors::ParseSignatureError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (prefix, sig_data) = value
+            .split_once(':')
+            .ok_or_else(|| Self::Err::UnknownKeyType { unknown_key_type: value.to_string() })?;
+        let prefix = prefix.trim().to_ascii_lowercase();
+        let scheme = prefix
+            .strip_prefix(RSA_SIGNATURE_PREFIX)
+            .and_then(|scheme| RsaScheme::from_str(scheme).ok())
+            .ok_or_else(|| Self::Err::UnknownKeyType { unknown_key_type: prefix.clone() })?;
+        Ok(Self { scheme, signature: Rsa2048Signature(decode_bs58(sig_data.trim())?) })
+    }
+}
+
+impl SecretKey {
+    /// Signs `data` with the RSA secret key using the given signature scheme.
+    /// [`SecretKey::sign`] always uses [`RsaScheme::Pkcs1v15`].
+    ///
+    /// For [`RsaScheme::Pss`] `data` must be a digest made with the scheme's
+    /// hash function.  Fails if the secret key isn't an RSA key.
+    pub fn sign_rsa(
+        &self,
+        data: &[u8],
+        scheme: RsaScheme,
+    ) -> Result<RsaSignature, crate::errors::SigningError> {
+        use crate::errors::SigningError;
+        let secret_key = match self {
+            SecretKey::RSA(secret_key) => secret_key,
+            _ => {
+                return Err(SigningError::KeyTypeMismatch {
+                    expected_key_type: KeyType::RSA2048,
+                    received_key_type: self.key_type(),
+                })
+            }
+        };
+        let signature = match scheme {
+            RsaScheme::Pkcs1v15 => {
+                let Signature::RSA(signature) = self.sign(data) else { unreachable!() };
+                signature
+            }
+            RsaScheme::Pss { hash, salt_len } => {
+                if data.len() != hash.output_len() {
+                    return Err(SigningError::MessageLength {
+                        expected: hash.output_len(),
+                        received: data.len(),
+                    });
+                }
+                let max_salt_len = hash.max_salt_len();
+                if usize::from(salt_len) > max_salt_len {
+                    return Err(SigningError::SaltLength {
+                        max: max_salt_len,
+                        received: usize::from(salt_len),
+                    });
+                }
+                let sign_data = secret_key
+                    .sign_with_rng(&mut OsRng, hash.pss(salt_len), data)
+                    .expect("digest and salt lengths are checked above");
+                Rsa2048Signature(<[u8; 256]>::try_from(sign_data.as_slice()).unwrap())
+            }
+        };
+        Ok(RsaSignature { scheme, signature })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_serialize_rsa2048() {
+        use sha2::Digest;
+        let data = sha2::Sha256::digest(b"123").to_vec();
+
+        let sk = SecretKey::from_seed(KeyType::RSA2048, "test");
+        let pk = sk.public_key();
+        let expected = "\"rsa2048:2TuPVgMCHJy5atawrsADEzjP7MCVbyyCA89UW6Wvjp9HrBuhZpGCRvEqExjN4wDfrT97k75BySeWiWgDoRmWBCVMQzCNFWQcfVmzeeZJFnVVceSziJsciYeCEeJGzjQnWBj4PEESKNgdKGWrQyUckRvknPQE3v7GVp9tXRPL81nLAgNm29E4SQ3u6ZV3DzJTCnnsoW75H8vdMMRY3zNzpTWKjEkMYA9qow6nnpS9asJ3HqXshDh3ookoAqzYgVwYmh2CDYFyw3cdwzimFFTYv3STud6erWxiMogeqP2XNnUyFYPKRWrhrrY966QDk4mEz1JgvBN9U4Vh5tsJGZLrZQPpt1owEjrGuCB6iqZQFwKxxjmNTcCZXZZn2WbdYVnSXGFR68uAjtPmHktzwS\"";
+        assert_eq!(serde_json::to_string(&pk).unwrap(), expected);
+        assert_eq!(pk, serde_json::from_str(expected).unwrap());
+        let pk2: PublicKey = pk.to_string().parse().unwrap();
+        assert_eq!(pk, pk2);
+
+        let expected = "\"rsa2048:riiewRJm2wpE3rWTs1ikUc83so8ZXMX8vp9dUTnRgMC8GyfLr99MgiVFAbK3mdNq6mGY5dNdUfn3anQVSqFHL4sPbZD4w7QBx5Dzj4MzqJ8LjqmiKxE64G9tNDjfzkyYdinPssorC9yab7EhBMe24m3dMSnwHBJHQsXXaGibBtJUBcgPCbwYerZjfJB7TjMrj7WF1A2Q9SNdLUMYNX5CuKbWnpmrgFdkUzR1rZjrcgzSyUs4LrWwPBy2uA8PjJLwRabvoPpSr6hTMoHjeGMnQsLbVxKs7SC5aucdXru6ox9jJeD9Jackd5HKjAmobBaKiR1i9f7EsoxfsmibsqML8B5fFuHCRzMT6Ea5oEETevn4H5uBszJtrPJQpM5kwNogcNchHhK8GG2FZDGY5bsZuJEvzrWeuK7XR1ef1JmAmCtSqQNLe42CkqvBun8Cwj61Gf2rkvU2He1Wc6Lg81CwQKLUZTFRDXkdmaJEjAdweXhcksbMhajDp1D5mHtL3LY3FvxvgZpHxVq4gnKQTQenCvmgoH6JAJNQK5pmP68hMaJ4EZ45LgCzfzNs5eYYq3jqUQHGY7mvKi7E4ZFkY8fmgk5VQWcTyb3WeiqXzSYB79c2cR4XSUgmXiaFnLUYM1kqaNzeUhiprCTC43k9MhX5kMw3VRcg2RzrdnofHetPn75MPeR4g9i4kooZyRRkEvdg4YAWL6rhYQ5vV99cbQvTZSAzYTasiHfUKLkB76yoXJiok57tAjbz9XBGgWeqGRF8UFFcMDw8KJqrrEA4E1FhYEEYNR84kuU4ZwnnJakBCXf1UoYC7RKJEiWtcBqcL3Epcp3x6d4qxLij3M1pCDeFPZPYyMqYPvM8yB6GfMVwcycJSxWjK7cxmVRPF9WT3HyVNqFHA4o1aXHJ9LGMgDdVCUSk1QfEC1kLxMMFZMVY6RK6ycUPmotJxbJgBL9SAFypzNg63tipocAXucqaJ3NQrA5ujLnV4GhrmwF9Eo6T7FH9qgqsKZV1FN7m83TtXUuRqSDMdpDLLNotcC4MQ6nFH46R73ct8CE4ibn6j4dtPMMJrEuWQqAE8tqpvGJoxifvVfwmtJMvozTTu69DgXn38MHZL2f3K25M7iW4yWiZjve4b7AFXhnaaKQuCwoZ6CNf31X2STT29wFvw6HMZNZt4WdXMxUrgP5mkM8r2Fio8iEQUbSfhrAj3SuZXDV3xiRYRXb45cL7umoZ446YctmQuyHzaRfP8yLsy3Y7Bn8GGTj4bbzPNhT4r4QHitobymKScePdFTms4P8HNogebkBf4K7QrNSJxA4EVRgf9aP4KejHUfhq9v7pLGsfXv3rGaxRZnCNrgTYY215e8FoJcx8mQGvykCRejto8Gghp1gw5n5eC3ddMUiYqphteoYfuhVYfiweMDSiRrajko4JAxuXpvHRVeTwSypPYUkiazcog7z8bgPSq1FNS8Vnqhyx4oSj5rBGXTK8y7MR9zPB8yN78DacxPBBLfUcMvVan4GueCi2wxq9KL8XMj8DvDccBBotc8c1jftgaYdLqESVqpiKj3ZSu8Ui3SpdhELMFzk22kwRXN2p9nK78u94Gpp44J9upyiNpHsLbkB3kpT4vtvxa8P9H1YhMqVRB2k9EhVHUwATRVb3uoznRqXVnXmE8cq\"";
+        assert_eq!(serde_json::to_string(&sk).unwrap(), expected);
+        assert_eq!(sk, serde_json::from_str(expected).unwrap());
+
+        let signature = sk.sign(&data);
+        let expected = "\"rsa2048:9UXu2UtEzfgJWw5goaHcjAueJcRkwNS9VPHsF1Re2MR8p7WcA9Q77DTPAMWXkDnEsaebWFwrQHqqk8jAZfLsZDTBmDQ28XNsPgsx3wJkwrujYT5o99Zf6J1SbFK3umfzgo26BNWGLD44nrqhFJDwy1UdXqQPMKGKs7P56g2dqbEe3daoVze6UrhHQAdLbEXN9BQJBkNz254MLey7pzbAforMfoqy2S3RdvgFRQuXdgHbsXSHJEemmQEVpMiMvDW5Hz4vVMx3XaLkLLUQfqpT9Tom6NbGsNfPn7M1Ge1xXEFs25Zcqv3e7mq5Ps8pXovCexeznHJz5VSkDGY2h2r6tpACjDM2LW\"";
+        assert_eq!(serde_json::to_string(&signature).unwrap(), expected);
+        assert_eq!(signature, serde_json::from_str(expected).unwrap());
+        let signature_str: String = signature.to_string();
+        let signature2: Signature = signature_str.parse().unwrap();
+        assert_eq!(signature, signature2);
+    }
+
+    #[test]
+    fn test_invalid_rsa_data() {
+        let invalid = "\"rsa2048:riiewRJm2wpE3rWTs1ikUc83so8ZXMX8vp9dUTnRgMC8GyfLr99MgiVFAbK3mdNq6mGY5dNdUfn3anQVSqFHL4sPbZD4w7QBx5Dzj4MzqJ8LjqmiKxE64G9tNDjfzkyYdinPssorC9yab7EhBMe24m3dMSnwHBJHQsXXaGibBtJUBcgPCbwYerZjfJB7TjMrj7WF1A2Q9SNdLUMYNX5CuKbWnpmrgFdkUzR1rZjrcgzSyUs4LrWwPBy2uA8PjJLwRabvoPpSr6hTMoHjeGMnQsLbVxKs7SC5aucdXru6ox9jJeD9Jackd5HKjAmobBaKiR1i9f7EsoxfsmibsqML8B5fFuHCRzMT6Ea5oEETevn4H5uBszJtrPJQpM5kwNogcNchHhK8GG2FZDGY5bsZuJEvzrWeuK7XR1ef1JmAmCtSqQNLe42CkqvBun8Cwj61Gf2rkvU2He1Wc6Lg81CwQKLUZTFRDXkdmaJEjAdweXhcksbMhajDp1D5mHtL3LY3FvxvgZpHxVq4gnKQTQenCvmgoH6JAJNQK5pmP68hMaJ4EZ45LgCzfzNs5eYYq3jqUQHGY7mvKi7E4ZFkY8fmgk5VQWcTyb3WeiqXzSYB79c2cR4XSUgmXiaFnLUYM1kqaNzeUhiprCTC43k9MhX5kMw3VRcg2RzrdnofHetPn75MPeR4g9i4kooZyRRkEvdg4YAWL6rhYQ5vV99cbQvTZSAzYTasiHfUKLkB76yoXJiok57tAjbz9XBGgWeqGRF8UFFcMDw8KJqrrEA4E1FhYEEYNR84kuU4ZwnnJakBCXf1UoYC7RKJEiWtcBqcL3Epcp3x6d4qxLij3M1pCDeFPZPYyMqYPvM8yB6GfMVwcycJSxWjK7cxmVRPF9WT3HyVNqFHA4o1aXHJ9LGMgDdVCUSk1QfEC1kLxMMFZMVY6RK6ycUPmotJxbJgBL9SAFypzNg63tipocAXucqaJ3NQrA5ujLnV4GhrmwF9Eo6T7FH9qgqsKZV1FN7m83TtXUuRqSDMdpDLLNotcC4MQ6nFH46R73ct8CE4ibn6j4dtPMMJrEuWQqAE8tqpvGJoxifvVfwmtJMvozTTu69DgXn38MHZL2f3K25M7iW4yWiZjve4b7AFXhnaaKQuCwoZ6CNf31X2STT29wFvw6HMZNZt4WdXMxUrgP5mkM8r2Fio8iEQUbSfhrAj3SuZXDV3xiRYRXb45cL7umoZ446YctmQuyHzaRfP8yLsy3Y7Bn8GGTj4bbzPNhT4r4QHitobymKScePdFTms4P8HNogebkBf4K7QrNSJxA4EVRgf9aP4KejHUfhq9v7pLGsfXv3rGaxRZnCNrgTYY215e8FoJcx8mQGvykCRejto8Gghp1gw5n5eC3ddMUiYqphteoYfuhVYfiweMDSiRrajko4JAxuXpvHRVeTwSypPYUkiazcog7z8bgPSq1FNS8Vnqhyx4oSj5rBGXTK8y7MR9zPB8yN78DacxPBBLfUcMvVan4GueCi2wxq9KL8XMj8DvDccBBotc8c1jftgaYdLqESVqpiKj3ZSu8Ui3SpdhELMFzk22kwRXN2p9nK78u94Gpp44J9upyiNpHsLbkB3kpT4vtvxa8P9H1YhMqVRB2k9EhVHUwATRVb3uoznRqXVnXmE8cq\"";
+        assert!(serde_json::from_str::<PublicKey>(invalid).is_err());
+        assert!(serde_json::from_str::<SecretKey>(invalid).is_ok());
+        assert!(serde_json::from_str::<Signature>(invalid).is_err());
+    }
+
+    #[test]
+    fn test_rsa_scheme_string_roundtrip() {
+        for scheme in RsaScheme::SUPPORTED {
+            assert_eq!(RsaScheme::from_str(&scheme.to_string()).unwrap(), scheme);
+        }
+        let pss_sha512_20 = RsaScheme::Pss { hash: RsaPssHash::Sha512, salt_len: 20 };
+        assert_eq!(RsaScheme::Pkcs1v15.to_string(), "pkcs1v15");
+        assert_eq!(RsaScheme::PSS_SHA256.to_string(), "pss-sha256");
+        assert_eq!(pss_sha512_20.to_string(), "pss-sha512-20");
+        assert_eq!(RsaScheme::from_str("PSS-SHA256").unwrap(), RsaScheme::PSS_SHA256);
+        assert_eq!(RsaScheme::from_str("pss-sha512-64").unwrap(), RsaScheme::PSS_SHA512);
+        assert_eq!(RsaScheme::from_str("pss-sha512-20").unwrap(), pss_sha512_20);
+        for unknown in ["pss-sha384", "pss-sha256-", "pss-sha256-x", "pss", "pkcs1v15-32"] {
+            assert!(
+                matches!(
+                    RsaScheme::from_str(unknown),
+                    Err(crate::errors::ParseRsaSchemeError::UnknownScheme { .. })
+                ),
+                "{unknown}"
+            );
+        }
+
+        let sk = SecretKey::from_seed(KeyType::RSA2048, "test");
+        for scheme in RsaScheme::SUPPORTED.into_iter().chain([pss_sha512_20]) {
+            let data = match scheme {
+                RsaScheme::Pss { hash, .. } => vec![7u8; hash.output_len()],
+                RsaScheme::Pkcs1v15 => vec![7u8; 32],
+            };
+            let signature = sk.sign_rsa(&data, scheme).unwrap();
+            assert_eq!(signature.scheme, scheme);
+            assert!(signature.verify(&data, &sk.public_key()), "{scheme}");
+        }
+    }
+
+    #[test]
+    fn test_rsa_pss() {
+        use crate::errors::SigningError;
+        use sha2::Digest;
+        let data = sha2::Sha256::digest(b"hello world").to_vec();
+
+        // Generated with Python's `cryptography` package using PSS with
+        // MGF1-SHA-256 and a 32-byte salt.
+        let public_key = PublicKey::from_str("rsa2048:2TuPVgMCHJy5atawrsADEzjP7MCVbyyCA89UW6Wvjp9HrB2A5fLWMgNcC6BA4q8eitTZDBMSP1frVrB4v27UVcf37mLkLcmDEDwm7XGzbw6MfU5ByXa5ueSDsZaJw4dpJKrpJdJAAqo58U3FcTWBANoVJDBcYc6gjj3DvxwAxqdwUJksnAad3Luu5DWA1xKFQbM77qLvbVEMaFzKnsc6JY7dxFVBAuxyqd41ACGpwYK9gyxskC5dyPuobwyNtgXVKoFTRQZitMBPv2qXLxdCMcDEg6MDRTbQseWR6bBchVgxQQrpunG3aUCaRgupcMrvYuxjvkP8wx96vpTjfTVx2eqM3BxapC7EjDN219AcX39ik4fmcXhrv4PmyemQbQyy2j6yeXPysdmQLMT2Nk").unwrap();
+        let signature = RsaSignature::from_str("rsa2048-pss-sha256:7X7qf4miRfmZb19rPvQo18DHdVtRvsmAeRWGXEq9bHWaxZxGstrSkuYrXUHKeEX8zrbQp8FWCfp5NMNEC17vD9RNpbcyHtYxd7EUWFeFYMUEg5ex6tUNoipqLZsBz4nTz3AEbMNjeKccQznrHw34A5caL8it7AqnNUp6ZLaRDSTfjqUUvKQP77qmk6mhGvgR92PvcHrk1VsVVEB7xXTSGH4jsHqaohN9cgmaLm2Jt357JA3kBXkoZzAPNULKAGbwvcgquLqvX1pgfk9qu7Z4VcHX1y5pXqsMLxEdA4Lw6oL1o1F3aDzvuDYspxj2QHsSyijS49Qo6ZajJeiBVpBb9bxkupHvDT").unwrap();
+        assert_eq!(signature.scheme, RsaScheme::PSS_SHA256);
+        assert!(signature.verify(&data, &public_key));
+        assert!(!signature.verify(&sha2::Sha256::digest(b"hello"), &public_key));
+        assert!(signature.to_signature().is_none());
+        assert!(!Signature::RSA(signature.signature.clone()).verify(&data, &public_key));
+        let salt_20 = RsaScheme::Pss { hash: RsaPssHash::Sha256, salt_len: 20 };
+        assert!(!RsaSignature { scheme: salt_20, ..signature }.verify(&data, &public_key));
+
+        let sk = SecretKey::from_seed(KeyType::RSA2048, "test");
+        let signature = sk.sign_rsa(&data, RsaScheme::PSS_SHA256).unwrap();
+        assert!(signature.verify(&data, &sk.public_key()));
+        assert!(!signature.verify(&data, &PublicKey::from_seed(KeyType::ED25519, "test")));
+        let pkcs1v15 = sk.sign_rsa(&data, RsaScheme::Pkcs1v15).unwrap();
+        assert!(pkcs1v15.verify(&data, &sk.public_key()));
+        assert_eq!(pkcs1v15.to_signature().unwrap(), sk.sign(&data));
+
+        let bytes = borsh::to_vec(&signature).unwrap();
+        assert_eq!(RsaSignature::try_from_slice(&bytes).unwrap(), signature);
+        assert!(Signature::try_from_slice(&bytes).is_err());
+        let string = signature.to_string();
+        assert!(string.starts_with("rsa2048-pss-sha256:"));
+        assert_eq!(RsaSignature::from_str(&string).unwrap(), signature);
+        assert!(Signature::from_str(&string).is_err());
+
+        assert!(matches!(
+            sk.sign_rsa(&data[1..], RsaScheme::PSS_SHA256),
+            Err(SigningError::MessageLength { expected: 32, received: 31 })
+        ));
+        assert!(matches!(
+            sk.sign_rsa(&data, RsaScheme::Pss { hash: RsaPssHash::Sha256, salt_len: 223 }),
+            Err(SigningError::SaltLength { max: 222, received: 223 })
+        ));
+        assert!(sk
+            .sign_rsa(&data, RsaScheme::Pss { hash: RsaPssHash::Sha256, salt_len: 222 })
+            .unwrap()
+            .verify(&data, &sk.public_key()));
+        assert!(matches!(
+            SecretKey::from_seed(KeyType::ED25519, "test").sign_rsa(&data, RsaScheme::PSS_SHA256),
+            Err(SigningError::KeyTypeMismatch { .. })
+        ));
+    }
+}
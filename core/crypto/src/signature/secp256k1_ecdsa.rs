@@ -0,0 +1,699 @@
+//! secp256k1 ECDSA keys and signatures.
+
+use super::{
+    base58check_decode, base58check_encode, decode_bs58, deserialize_str_or_bytes,
+    serialize_str_or_bytes, Bs58, KeyType, PublicKey, SecretKey, Signature,
+};
+use crate::digest::Digest32;
+use borsh::{BorshDeserialize, BorshSerialize};
+use once_cell::sync::Lazy;
+use primitive_types::U256;
+use secp256k1::Message;
+use std::fmt::{Debug, Display, Formatter};
+use std::str::FromStr;
+use zeroize::Zeroizing;
+
+/// Full secp256k1 context used for signing and deriving public keys.  It's
+/// randomized on creation to protect signing against side-channel attacks,
+/// which makes it the more expensive context to set up.
+pub static SECP256K1: Lazy<secp256k1::Secp256k1<secp256k1::All>> =
+    Lazy::new(secp256k1::Secp256k1::new);
+
+/// Verification-only secp256k1 context used for verifying signatures and
+/// recovering public keys when the `secp256k1_verify_only` feature is enabled.
+///
+/// It's cheaper to create than [`SECP256K1`] and is initialized separately,
+/// so nodes which never sign with secp256k1 keys never pay for the full
+/// context.  The tradeoff is that processes which both sign and verify end up
+/// holding two contexts.
+#[cfg(feature = "secp256k1_verify_only")]
+pub static SECP256K1_VERIFY: Lazy<secp256k1::Secp256k1<secp256k1::VerifyOnly>> =
+    Lazy::new(secp256k1::Secp256k1::verification_only);
+
+/// Returns the context used for verifying secp256k1 signatures.
+#[cfg(not(feature = "secp256k1_verify_only"))]
+pub(super) fn secp256k1_verifier() -> &'static secp256k1::Secp256k1<secp256k1::All> {
+    &SECP256K1
+}
+
+/// Returns the context used for verifying secp256k1 signatures.
+#[cfg(feature = "secp256k1_verify_only")]
+pub(super) fn secp256k1_verifier() -> &'static secp256k1::Secp256k1<secp256k1::VerifyOnly> {
+    &SECP256K1_VERIFY
+}
+
+pub(crate) const PUBLIC_KEY_SECP256K1_LENGTH: usize = 64;
+
+#[derive(
+    Clone,
+    Eq,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    derive_more::AsRef,
+    derive_more::From,
+    BorshSerialize,
+    BorshDeserialize,
+)]
+#[cfg_attr(test, derive(bolero::TypeGenerator))]
+#[as_ref(forward)]
+pub struct Secp256K1PublicKey(pub(super) [u8; PUBLIC_KEY_SECP256K1_LENGTH]);
+
+/// The key is always stored as the 64-byte uncompressed `x || y` coordinates
+/// of the point (SEC1 uncompressed encoding without the `0x04` prefix), so
+/// keys constructed from different encodings of the same point compare and
+/// hash equal.
+impl Secp256K1PublicKey {
+    /// Constructs the key from its SEC1 encoding, either compressed (33 bytes)
+    /// or uncompressed (65 bytes), converting it to the canonical form.
+    pub fn from_sec1_bytes(data: &[u8]) -> Result<Self, crate::errors::ParseKeyError> {
+        let public_key = secp256k1::PublicKey::from_slice(data).map_err(|err| {
+            crate::errors::ParseKeyError::InvalidData { error_message: err.to_string() }
+        })?;
+        Ok(Self::from_secp256k1(&public_key))
+    }
+
+    fn from_secp256k1(public_key: &secp256k1::PublicKey) -> Self {
+        let mut key = Self([0; PUBLIC_KEY_SECP256K1_LENGTH]);
+        key.0.copy_from_slice(&public_key.serialize_uncompressed()[1..]);
+        key
+    }
+
+    /// Returns the key in the canonical uncompressed form.  The operation is
+    /// idempotent; keys which don't represent a valid curve point are returned
+    /// unchanged.
+    pub fn normalize(&self) -> Self {
+        let mut sec1 = [4u8; PUBLIC_KEY_SECP256K1_LENGTH + 1];
+        sec1[1..].copy_from_slice(&self.0);
+        match secp256k1::PublicKey::from_slice(&sec1) {
+            Ok(public_key) => Self::from_secp256k1(&public_key),
+            Err(_) => self.clone(),
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for Secp256K1PublicKey {
+    type Error = crate::errors::ParseKeyError;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        data.try_into().map(Self).map_err(|_| Self::Error::InvalidLength {
+            expected_length: PUBLIC_KEY_SECP256K1_LENGTH,
+            received_length: data.len(),
+        })
+    }
+}
+
+impl std::fmt::Debug for Secp256K1PublicKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        Display::fmt(&Bs58(&self.0), f)
+    }
+}
+
+impl_bs58_serde!(Secp256K1PublicKey, PUBLIC_KEY_SECP256K1_LENGTH, crate::errors::ParseKeyError);
+impl_from_array!(Secp256K1PublicKey, PUBLIC_KEY_SECP256K1_LENGTH);
+
+const SECP256K1_N: U256 =
+    U256([0xbfd25e8cd0364141, 0xbaaedce6af48a03b, 0xfffffffffffffffe, 0xffffffffffffffff]);
+
+// Half of SECP256K1_N + 1.
+const SECP256K1_N_HALF_ONE: U256 =
+    U256([0xdfe92f46681b20a1, 0x5d576e7357a4501d, 0xffffffffffffffff, 0x7fffffffffffffff]);
+
+pub(crate) const SECP256K1_SIGNATURE_LENGTH: usize = 65;
+
+#[derive(
+    Clone,
+    Eq,
+    PartialEq,
+    Hash,
+    derive_more::From,
+    derive_more::Into,
+    BorshSerialize,
+    BorshDeserialize,
+)]
+pub struct Secp256K1Signature(pub(super) [u8; SECP256K1_SIGNATURE_LENGTH]);
+
+impl Secp256K1Signature {
+    /// Returns `r`, i.e. bytes `0..32` of the signature, as a 32-byte
+    /// big-endian integer (the standard compact ECDSA encoding).
+    pub fn r(&self) -> [u8; 32] {
+        self.0[0..32].try_into().unwrap()
+    }
+
+    /// Returns `s`, i.e. bytes `32..64` of the signature, as a 32-byte
+    /// big-endian integer (the standard compact ECDSA encoding).
+    pub fn s(&self) -> [u8; 32] {
+        self.0[32..64].try_into().unwrap()
+    }
+
+    /// Returns the recovery id, i.e. the last byte of the signature.  It's
+    /// in `0..=3` for valid signatures, see [`Secp256K1Signature::is_degenerate`].
+    pub fn recovery_id(&self) -> u8 {
+        self.0[64]
+    }
+
+    /// Checks that `r` and `s` are in range, i.e. below the curve order `n`.
+    /// With `reject_upper` set `s` must also be at most `n / 2` which rejects
+    /// malleable signatures.
+    ///
+    /// Both are read with [`Secp256K1Signature::r`] and
+    /// [`Secp256K1Signature::s`]; the `From<[u8; 32]>` conversion of [`U256`]
+    /// is big-endian as well.
+    pub fn check_signature_values(&self, reject_upper: bool) -> bool {
+        let r = U256::from(self.r());
+        let s = U256::from(self.s());
+
+        let s_check = if reject_upper {
+            // Reject upper range of s values (ECDSA malleability)
+            SECP256K1_N_HALF_ONE
+        } else {
+            SECP256K1_N
+        };
+
+        r < SECP256K1_N && s < s_check
+    }
+
+    /// Returns whether the signature is malformed in a way that makes it
+    /// unverifiable regardless of the message and key: `r` or `s` is zero or
+    /// not below the curve order, or the recovery id is above 3.
+    ///
+    /// [`Signature::verify`] is guaranteed to return `false` rather than panic
+    /// for such signatures (e.g. `[4; 65]`, see the
+    /// `regression_signature_verification_originally_failed` test); this lets
+    /// callers filter them out cheaply beforehand.  Unlike
+    /// [`Signature::validate_structure`] high `s` values are not rejected.
+    pub fn is_degenerate(&self) -> bool {
+        self.r() == [0; 32]
+            || self.s() == [0; 32]
+            || self.recovery_id() > 3
+            || !self.check_signature_values(false)
+    }
+
+    /// Converts the signature to the low-S form required e.g. by BIP-62: if
+    /// `s` is above `n / 2` it's replaced with `n - s` and the parity of the
+    /// recovery id is flipped so that the same public key is still recovered.
+    ///
+    /// Afterwards `check_signature_values(true)` holds for any signature which
+    /// isn't [degenerate](Secp256K1Signature::is_degenerate); degenerate
+    /// signatures are left unchanged.
+    pub fn normalize_s(&mut self) {
+        if self.is_degenerate() {
+            return;
+        }
+        let Ok(mut signature) = secp256k1::ecdsa::Signature::from_compact(&self.0[0..64]) else {
+            return;
+        };
+        signature.normalize_s();
+        let normalized = signature.serialize_compact();
+        if normalized != self.0[0..64] {
+            self.0[0..64].copy_from_slice(&normalized);
+            self.0[64] ^= 1;
+        }
+    }
+
+    /// Returns the standard 64-byte compact ECDSA signature, i.e. big-endian
+    /// `r || s`, without the trailing recovery id.
+    pub fn to_standard_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes.copy_from_slice(&self.0[0..64]);
+        bytes
+    }
+
+    /// Builds a recoverable signature out of a standard 64-byte compact ECDSA
+    /// signature (big-endian `r || s`).
+    ///
+    /// The recovery id isn't part of a standard signature so it's recomputed
+    /// by finding the one for which the signer's `public_key` is recovered from
+    /// `msg`.  Returns an error if there's no such recovery id, i.e. the
+    /// signature doesn't sign `msg` with `public_key`.
+    ///
+    /// Note that [`Signature::verify`] only checks that the recovery id is in
+    /// the valid `0..=3` range and otherwise verifies against the given public
+    /// key, so it's only [`Secp256K1Signature::recover`] which depends on the
+    /// recovery id being correct.
+    pub fn from_standard_bytes(
+        bytes: &[u8; 64],
+        msg: [u8; 32],
+        public_key: &Secp256K1PublicKey,
+    ) -> Result<Self, crate::errors::ParseSignatureError> {
+        for rec_id in 0..4u8 {
+            let mut data = [0u8; SECP256K1_SIGNATURE_LENGTH];
+            data[0..64].copy_from_slice(bytes);
+            data[64] = rec_id;
+            let signature = Self(data);
+            if signature.recover(msg).map_or(false, |recovered| recovered == *public_key) {
+                return Ok(signature);
+            }
+        }
+        Err(crate::errors::ParseSignatureError::InvalidData {
+            error_message: "signature doesn't match the public key".to_string(),
+        })
+    }
+
+    /// Recovers the public key which signed the 32-byte digest `msg`.  Accepts
+    /// either [`Digest32`] or a `[u8; 32]` array.
+    pub fn recover(
+        &self,
+        msg: impl Into<Digest32>,
+    ) -> Result<Secp256K1PublicKey, crate::errors::ParseSignatureError> {
+        let recovery_id =
+            secp256k1::ecdsa::RecoveryId::from_i32(i32::from(self.0[64])).map_err(|err| {
+                crate::errors::ParseSignatureError::InvalidData { error_message: err.to_string() }
+            })?;
+        let recoverable_sig =
+            secp256k1::ecdsa::RecoverableSignature::from_compact(&self.0[0..64], recovery_id)
+                .map_err(|err| crate::errors::ParseSignatureError::InvalidData {
+                    error_message: err.to_string(),
+                })?;
+        let msg = Message::from(msg.into());
+
+        let res = secp256k1_verifier()
+            .recover_ecdsa(&msg, &recoverable_sig)
+            .map_err(|err| crate::errors::ParseSignatureError::InvalidData {
+                error_message: err.to_string(),
+            })?
+            .serialize_uncompressed();
+
+        // Can not fail
+        let pk = Secp256K1PublicKey::try_from(&res[1..65]).unwrap();
+
+        Ok(pk)
+    }
+
+    /// Verifies the signature of the 32-byte digest `msg` against an explicit
+    /// public key, i.e. without recovering the key from the signature.  Only
+    /// the standard 64-byte `r || s` part is used and the recovery id is
+    /// ignored.
+    pub fn verify_standard(&self, msg: [u8; 32], public_key: &Secp256K1PublicKey) -> bool {
+        Self::verify_standard_bytes(&self.to_standard_bytes(), msg, public_key)
+    }
+
+    /// Same as [`Secp256K1Signature::verify_standard`] but takes a standard
+    /// 64-byte compact ECDSA signature (big-endian `r || s`), e.g. one coming
+    /// from a system which doesn't record the recovery id.
+    ///
+    /// As with [`Signature::verify`], signatures with high `s` are rejected.
+    pub fn verify_standard_bytes(
+        signature: &[u8; 64],
+        msg: [u8; 32],
+        public_key: &Secp256K1PublicKey,
+    ) -> bool {
+        let Ok(signature) = secp256k1::ecdsa::Signature::from_compact(signature) else {
+            return false;
+        };
+        let mut sec1 = [4u8; PUBLIC_KEY_SECP256K1_LENGTH + 1];
+        sec1[1..].copy_from_slice(&public_key.0);
+        let Ok(public_key) = secp256k1::PublicKey::from_slice(&sec1) else {
+            return false;
+        };
+        let msg = Message::from(Digest32::from(msg));
+        secp256k1_verifier().verify_ecdsa(&msg, &signature, &public_key).is_ok()
+    }
+}
+
+impl TryFrom<&[u8]> for Secp256K1Signature {
+    type Error = crate::errors::ParseSignatureError;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self(data.try_into().map_err(|_| Self::Error::InvalidLength {
+            expected_length: 65,
+            received_length: data.len(),
+        })?))
+    }
+}
+
+impl Debug for Secp256K1Signature {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        Display::fmt(&Bs58(&self.0), f)
+    }
+}
+
+impl_bs58_serde!(
+    Secp256K1Signature,
+    SECP256K1_SIGNATURE_LENGTH,
+    crate::errors::ParseSignatureError
+);
+impl_from_array!(Secp256K1Signature, SECP256K1_SIGNATURE_LENGTH);
+
+/// Signs `digest` producing recoverable secp256k1 signature with the recovery
+/// id in the last byte.
+pub(super) fn sign_secp256k1(secret_key: &secp256k1::SecretKey, digest: Digest32) -> Signature {
+    let signature = SECP256K1.sign_ecdsa_recoverable(&Message::from(digest), secret_key);
+    let (rec_id, data) = signature.serialize_compact();
+    let mut buf = [0; 65];
+    buf[0..64].copy_from_slice(&data[0..64]);
+    buf[64] = rec_id.to_i32() as u8;
+    Signature::SECP256K1(Secp256K1Signature(buf))
+}
+
+/// Version byte of mainnet secret keys in Wallet Import Format.
+const WIF_VERSION: u8 = 0x80;
+/// Suffix marking that the WIF key corresponds to a compressed public key.
+const WIF_COMPRESSED_FLAG: u8 = 0x01;
+/// Length of the WIF payload of a compressed key: version byte, secret key and
+/// compression flag.
+const WIF_MAX_PAYLOAD_LENGTH: usize = 34;
+
+impl SecretKey {
+    /// Signs an already computed 32-byte digest with a secp256k1 key.
+    ///
+    /// `digest` is signed as is, e.g. it must not be hashed again before
+    /// verifying with [`Signature::verify_secp256k1_digest`].  Returns
+    /// [`SigningError::KeyTypeMismatch`](crate::errors::SigningError::KeyTypeMismatch)
+    /// for other key types.
+    ///
+    /// ```
+    /// use unc_crypto::{Digest32, KeyType, SecretKey};
+    ///
+    /// let secret_key = SecretKey::from_seed(KeyType::SECP256K1, "test");
+    /// let digest = Digest32::from_sha256(b"message");
+    /// let signature = secret_key.sign_secp256k1_digest(digest).unwrap();
+    /// assert!(signature.verify_secp256k1_digest(digest, &secret_key.public_key()));
+    /// ```
+    pub fn sign_secp256k1_digest(
+        &self,
+        digest: impl Into<Digest32>,
+    ) -> Result<Signature, crate::errors::SigningError> {
+        match self {
+            SecretKey::SECP256K1(secret_key) => Ok(sign_secp256k1(secret_key, digest.into())),
+            _ => Err(crate::errors::SigningError::KeyTypeMismatch {
+                expected_key_type: KeyType::SECP256K1,
+                received_key_type: self.key_type(),
+            }),
+        }
+    }
+
+    /// Parses secp256k1 secret key in the Bitcoin Wallet Import Format, i.e.
+    /// base58check of `0x80 || key [|| 0x01]`.  Both the compressed and
+    /// uncompressed form are accepted; the compression flag is dropped since
+    /// it only affects how the public key is encoded.
+    pub fn secp256k1_from_wif(wif: &str) -> Result<Self, crate::errors::ParseKeyError> {
+        use crate::errors::ParseKeyError;
+        let payload = base58check_decode(wif)?;
+        let Some((&WIF_VERSION, mut key)) = payload.split_first() else {
+            return Err(ParseKeyError::InvalidData {
+                error_message: "invalid WIF version byte".to_string(),
+            });
+        };
+        if key.len() == secp256k1::constants::SECRET_KEY_SIZE + 1
+            && key.last() == Some(&WIF_COMPRESSED_FLAG)
+        {
+            key = &key[..secp256k1::constants::SECRET_KEY_SIZE];
+        }
+        if key.len() != secp256k1::constants::SECRET_KEY_SIZE {
+            return Err(ParseKeyError::InvalidLength {
+                expected_length: secp256k1::constants::SECRET_KEY_SIZE,
+                received_length: key.len(),
+            });
+        }
+        let secret_key = secp256k1::SecretKey::from_slice(key)
+            .map_err(|err| ParseKeyError::InvalidData { error_message: err.to_string() })?;
+        Ok(SecretKey::SECP256K1(secret_key))
+    }
+
+    /// Encodes secp256k1 secret key in the Bitcoin Wallet Import Format.
+    /// `compressed` tells whether the key is marked as corresponding to
+    /// a compressed public key.
+    pub fn to_wif(&self, compressed: bool) -> Result<String, crate::errors::WifError> {
+        let SecretKey::SECP256K1(secret_key) = self else {
+            return Err(crate::errors::WifError::UnsupportedKeyType { key_type: self.key_type() });
+        };
+        // Reserve the whole payload upfront so that the secret isn't left behind
+        // in a reallocated buffer.
+        let mut payload = Zeroizing::new(Vec::with_capacity(WIF_MAX_PAYLOAD_LENGTH));
+        payload.push(WIF_VERSION);
+        payload.extend_from_slice(Zeroizing::new(secret_key.secret_bytes()).as_slice());
+        if compressed {
+            payload.push(WIF_COMPRESSED_FLAG);
+        }
+        Ok(base58check_encode(&payload))
+    }
+}
+
+impl Signature {
+    /// Verifies secp256k1 signature of an already computed 32-byte digest, see
+    /// [`SecretKey::sign_secp256k1_digest`].  Returns `false` if either the
+    /// signature or the key isn't secp256k1.
+    pub fn verify_secp256k1_digest(
+        &self,
+        digest: impl Into<Digest32>,
+        public_key: &PublicKey,
+    ) -> bool {
+        matches!((self, public_key), (Signature::SECP256K1(_), PublicKey::SECP256K1(_)))
+            && self.verify(digest.into().as_bytes(), public_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_serialize_secp256k1() {
+        use sha2::Digest;
+        let data = sha2::Sha256::digest(b"123").to_vec();
+
+        let sk = SecretKey::from_seed(KeyType::SECP256K1, "test");
+        let pk = sk.public_key();
+        let expected = "\"secp256k1:5ftgm7wYK5gtVqq1kxMGy7gSudkrfYCbpsjL6sH1nwx2oj5NR2JktohjzB6fbEhhRERQpiwJcpwnQjxtoX3GS3cQ\"";
+        assert_eq!(serde_json::to_string(&pk).unwrap(), expected);
+        assert_eq!(pk, serde_json::from_str(expected).unwrap());
+        let pk2: PublicKey = pk.to_string().parse().unwrap();
+        assert_eq!(pk, pk2);
+
+        let expected = "\"secp256k1:X4ETFKtQkSGVoZEnkn7bZ3LyajJaK2b3eweXaKmynGx\"";
+        assert_eq!(serde_json::to_string(&sk).unwrap(), expected);
+        assert_eq!(sk, serde_json::from_str(expected).unwrap());
+
+        let signature = sk.sign(&data);
+        let expected = "\"secp256k1:5N5CB9H1dmB9yraLGCo4ZCQTcF24zj4v2NT14MHdH3aVhRoRXrX3AhprHr2w6iXNBZDmjMS1Ntzjzq8Bv6iBvwth6\"";
+        assert_eq!(serde_json::to_string(&signature).unwrap(), expected);
+        assert_eq!(signature, serde_json::from_str(expected).unwrap());
+        let signature_str: String = signature.to_string();
+        let signature2: Signature = signature_str.parse().unwrap();
+        assert_eq!(signature, signature2);
+    }
+
+    #[test]
+    fn test_secp256k1_public_key_normalize() {
+        let sk = SecretKey::from_seed(KeyType::SECP256K1, "test");
+        let SecretKey::SECP256K1(secret_key) = &sk else { unreachable!() };
+        let point = secp256k1::PublicKey::from_secret_key(&SECP256K1, secret_key);
+
+        let compressed = Secp256K1PublicKey::from_sec1_bytes(&point.serialize()).unwrap();
+        let uncompressed =
+            Secp256K1PublicKey::from_sec1_bytes(&point.serialize_uncompressed()).unwrap();
+        assert_eq!(compressed, uncompressed);
+        assert_eq!(&compressed, sk.public_key().unwrap_as_secp256k1());
+        assert_eq!(PublicKey::SECP256K1(compressed.clone()), sk.public_key());
+        assert_eq!(compressed.normalize(), compressed);
+        assert_eq!(compressed.normalize().normalize(), compressed);
+
+        let invalid = Secp256K1PublicKey([0; 64]);
+        assert_eq!(invalid.normalize(), invalid);
+        assert!(matches!(
+            Secp256K1PublicKey::from_sec1_bytes(&[2; 32]),
+            Err(crate::errors::ParseKeyError::InvalidData { .. })
+        ));
+    }
+
+    #[test]
+    fn test_secp256k1_standard_bytes() {
+        use sha2::Digest;
+        let data: [u8; 32] = sha2::Sha256::digest(b"123").into();
+        let sk = SecretKey::from_seed(KeyType::SECP256K1, "test");
+        let pk = sk.public_key();
+        let Signature::SECP256K1(signature) = sk.sign(&data) else { unreachable!() };
+
+        let standard = signature.to_standard_bytes();
+        assert_eq!(&standard[..], &signature.0[..64]);
+        let restored =
+            Secp256K1Signature::from_standard_bytes(&standard, data, pk.unwrap_as_secp256k1())
+                .unwrap();
+        assert_eq!(restored, signature);
+
+        let other = PublicKey::from_seed(KeyType::SECP256K1, "other");
+        assert!(Secp256K1Signature::from_standard_bytes(
+            &standard,
+            data,
+            other.unwrap_as_secp256k1()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_check_signature_values_boundaries() {
+        use hex_literal::hex;
+
+        let n: [u8; 32] = hex!("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141");
+        let n_minus_one: [u8; 32] =
+            hex!("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140");
+        let half_n: [u8; 32] =
+            hex!("7fffffffffffffffffffffffffffffff5d576e7357a4501ddfe92f46681b20a0");
+        let half_n_plus_one: [u8; 32] =
+            hex!("7fffffffffffffffffffffffffffffff5d576e7357a4501ddfe92f46681b20a1");
+        let one: [u8; 32] =
+            hex!("0000000000000000000000000000000000000000000000000000000000000001");
+
+        fn check(r: [u8; 32], s: [u8; 32], reject_upper: bool) -> bool {
+            let mut bytes = [0u8; SECP256K1_SIGNATURE_LENGTH];
+            bytes[..32].copy_from_slice(&r);
+            bytes[32..64].copy_from_slice(&s);
+            Secp256K1Signature(bytes).check_signature_values(reject_upper)
+        }
+
+        assert!(check(n_minus_one, one, true));
+        assert!(!check(n, one, true));
+        assert!(!check(n, one, false));
+
+        assert!(check(one, half_n, true));
+        assert!(!check(one, half_n_plus_one, true));
+        assert!(check(one, half_n_plus_one, false));
+        assert!(check(one, n_minus_one, false));
+        assert!(!check(one, n, false));
+
+        // Values are read as big-endian.  Had they been read as little-endian,
+        // `half_n` would be above `n / 2` just like its byte-reversed form is.
+        let mut reversed = half_n;
+        reversed.reverse();
+        assert!(!check(one, reversed, true));
+    }
+
+    #[test]
+    fn test_secp256k1_signature_accessors() {
+        use hex_literal::hex;
+        let r = hex!("0102030405060708091011121314151617181920212223242526272829303132");
+        let s = hex!("3332313029282726252423222120191817161514131211100908070605040302");
+        let mut data = [0; 65];
+        data[..32].copy_from_slice(&r);
+        data[32..64].copy_from_slice(&s);
+        data[64] = 1;
+        let signature = Secp256K1Signature(data);
+        assert_eq!(signature.r(), r);
+        assert_eq!(signature.s(), s);
+        assert_eq!(signature.recovery_id(), 1);
+        // Big-endian: the most significant byte comes first.
+        assert_eq!(U256::from(signature.r()).byte(31), 0x01);
+        assert_eq!(U256::from(signature.r()).byte(0), 0x32);
+
+        let sk = SecretKey::from_seed(KeyType::SECP256K1, "test");
+        let Signature::SECP256K1(signature) = sk.sign(&[7; 32]) else { unreachable!() };
+        let standard = signature.to_standard_bytes();
+        assert_eq!(signature.r(), standard[..32]);
+        assert_eq!(signature.s(), standard[32..]);
+        assert!(signature.recovery_id() <= 3);
+    }
+
+    #[test]
+    fn test_secp256k1_normalize_s() {
+        for seed in ["test1", "test2", "test3"] {
+            let sk = SecretKey::from_seed(KeyType::SECP256K1, seed);
+            let pk = sk.public_key();
+            let msg = [7u8; 32];
+            let Signature::SECP256K1(signature) = sk.sign(&msg) else { unreachable!() };
+            assert!(signature.check_signature_values(true));
+
+            // Low-S signatures are left as they are.
+            let mut normalized = signature.clone();
+            normalized.normalize_s();
+            assert_eq!(normalized, signature);
+
+            // Build the malleated high-S counterpart, i.e. (r, n - s) with the
+            // recovery id parity flipped.
+            let mut high_s = signature.clone();
+            let mut s = [0u8; 32];
+            SECP256K1_N.overflowing_sub(U256::from(signature.s())).0.to_big_endian(&mut s);
+            high_s.0[32..64].copy_from_slice(&s);
+            high_s.0[64] ^= 1;
+            assert!(!high_s.check_signature_values(true));
+            assert!(high_s.check_signature_values(false));
+            assert_eq!(high_s.recover(msg).unwrap(), signature.recover(msg).unwrap());
+
+            high_s.normalize_s();
+            assert_eq!(high_s, signature);
+            assert!(high_s.check_signature_values(true));
+            assert!(Signature::SECP256K1(high_s).verify(&msg, &pk));
+        }
+
+        let mut degenerate = Secp256K1Signature([0; 65]);
+        degenerate.normalize_s();
+        assert_eq!(degenerate, Secp256K1Signature([0; 65]));
+    }
+
+    #[test]
+    fn test_secp256k1_verify_standard() {
+        let sk = SecretKey::from_seed(KeyType::SECP256K1, "test");
+        let PublicKey::SECP256K1(pk) = sk.public_key() else { unreachable!() };
+        let msg = [7u8; 32];
+        let Signature::SECP256K1(signature) = sk.sign(&msg) else { unreachable!() };
+        assert!(signature.verify_standard(msg, &pk));
+        let standard = signature.to_standard_bytes();
+        assert!(Secp256K1Signature::verify_standard_bytes(&standard, msg, &pk));
+
+        // The recovery id doesn't matter.
+        let mut other_recovery_id = signature.0;
+        other_recovery_id[64] ^= 1;
+        assert!(Secp256K1Signature(other_recovery_id).verify_standard(msg, &pk));
+
+        assert!(!signature.verify_standard([8u8; 32], &pk));
+        let PublicKey::SECP256K1(other_pk) = PublicKey::from_seed(KeyType::SECP256K1, "other")
+        else {
+            unreachable!()
+        };
+        assert!(!signature.verify_standard(msg, &other_pk));
+        assert!(!Secp256K1Signature::verify_standard_bytes(&[0; 64], msg, &pk));
+    }
+
+    #[test]
+    fn test_secp256k1_digest() {
+        use sha2::Digest;
+        let digest = Digest32::from_sha256(b"message");
+        let sk = SecretKey::from_seed(KeyType::SECP256K1, "test");
+        let pk = sk.public_key();
+        let signature = sk.sign_secp256k1_digest(digest).unwrap();
+        assert_eq!(signature, sk.sign(digest.as_bytes()));
+        assert!(signature.verify_secp256k1_digest(digest, &pk));
+        // Raw arrays are accepted as well.
+        assert!(signature.verify_secp256k1_digest(*digest.as_bytes(), &pk));
+        // Hashing the digest again is a different message.
+        let double_hashed: [u8; 32] = sha2::Sha256::digest(digest.as_bytes()).into();
+        assert!(!signature.verify_secp256k1_digest(double_hashed, &pk));
+
+        let sk = SecretKey::from_seed(KeyType::ED25519, "test");
+        assert!(matches!(
+            sk.sign_secp256k1_digest(digest),
+            Err(crate::errors::SigningError::KeyTypeMismatch {
+                expected_key_type: KeyType::SECP256K1,
+                received_key_type: KeyType::ED25519,
+            })
+        ));
+        assert!(!sk.sign(digest.as_bytes()).verify_secp256k1_digest(digest, &sk.public_key()));
+    }
+
+    #[test]
+    fn test_wif() {
+        use crate::errors::ParseKeyError;
+        // Test vector from https://en.bitcoin.it/wiki/Wallet_import_format
+        let key = hex::decode("0C28FCA386C7A227600B2FE50B7CAE11EC86D3BF1FBE471BE89827E19D72AA1D")
+            .unwrap();
+        let sk = SecretKey::SECP256K1(secp256k1::SecretKey::from_slice(&key).unwrap());
+        let uncompressed = "5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTJ";
+        let compressed = "KwdMAjGmerYanjeui5SHS7JkmpZvVipYvB2LJGU1ZxJwYvP98617";
+        assert_eq!(sk.to_wif(false).unwrap(), uncompressed);
+        assert_eq!(sk.to_wif(true).unwrap(), compressed);
+        assert_eq!(SecretKey::secp256k1_from_wif(uncompressed).unwrap(), sk);
+        assert_eq!(SecretKey::secp256k1_from_wif(compressed).unwrap(), sk);
+
+        let sk = SecretKey::from_random(KeyType::SECP256K1);
+        assert_eq!(SecretKey::secp256k1_from_wif(&sk.to_wif(true).unwrap()).unwrap(), sk);
+
+        assert!(matches!(
+            SecretKey::secp256k1_from_wif("5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTK"),
+            Err(ParseKeyError::ChecksumMismatch)
+        ));
+        assert!(matches!(
+            SecretKey::from_seed(KeyType::ED25519, "test").to_wif(true),
+            Err(crate::errors::WifError::UnsupportedKeyType { key_type: KeyType::ED25519 })
+        ));
+    }
+}
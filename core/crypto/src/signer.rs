@@ -1,3 +1,4 @@
+use crate::errors::SignerError;
 use crate::key_conversion::convert_secret_key;
 use crate::key_file::KeyFile;
 use crate::{KeyType, PublicKey, SecretKey, Signature};
@@ -7,9 +8,23 @@ use std::sync::Arc;
 use unc_account_id::AccountId;
 
 /// Generic signer trait, that can sign with some subset of supported curves.
+///
+/// Code which only needs to sign should take `&dyn Signer` rather than
+/// a [`SecretKey`] (which implements this trait too) so that the key may be
+/// kept elsewhere, e.g. in an HSM or behind a remote signing service.
 pub trait Signer: Sync + Send {
     fn public_key(&self) -> PublicKey;
-    fn sign(&self, data: &[u8]) -> Signature;
+
+    /// Signs `data`, returning an error if the signer can't, e.g. because the
+    /// data isn't a valid message for the key type or a remote signing
+    /// service is unavailable.
+    fn try_sign(&self, data: &[u8]) -> Result<Signature, SignerError>;
+
+    /// Same as [`Signer::try_sign`] for callers which treat a failure to sign
+    /// as a bug.  Panics on error.
+    fn sign(&self, data: &[u8]) -> Signature {
+        self.try_sign(data).unwrap_or_else(|err| panic!("{err}"))
+    }
 
     fn verify(&self, data: &[u8], signature: &Signature) -> bool {
         signature.verify(data, &self.public_key())
@@ -28,11 +43,11 @@ pub struct EmptySigner {}
 
 impl Signer for EmptySigner {
     fn public_key(&self) -> PublicKey {
-        PublicKey::empty(KeyType::ED25519)
+        PublicKey::empty(KeyType::default())
     }
 
-    fn sign(&self, _data: &[u8]) -> Signature {
-        Signature::empty(KeyType::ED25519)
+    fn try_sign(&self, _data: &[u8]) -> Result<Signature, SignerError> {
+        Ok(Signature::empty(KeyType::default()))
     }
 
     fn compute_vrf_with_proof(&self, _data: &[u8]) -> (crate::vrf::Value, crate::vrf::Proof) {
@@ -40,6 +55,23 @@ impl Signer for EmptySigner {
     }
 }
 
+impl Signer for SecretKey {
+    fn public_key(&self) -> PublicKey {
+        SecretKey::public_key(self)
+    }
+
+    fn try_sign(&self, data: &[u8]) -> Result<Signature, SignerError> {
+        Ok(SecretKey::try_sign(self, data)?)
+    }
+
+    /// # Panics
+    ///
+    /// VRF is only defined for ED25519 keys, panics for any other key type.
+    fn compute_vrf_with_proof(&self, data: &[u8]) -> (crate::vrf::Value, crate::vrf::Proof) {
+        convert_secret_key(self.unwrap_as_ed25519()).compute_vrf_with_proof(&data)
+    }
+}
+
 /// Signer that keeps secret key in memory.
 #[derive(Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
 pub struct InMemorySigner {
@@ -68,8 +100,8 @@ impl Signer for InMemorySigner {
         self.public_key.clone()
     }
 
-    fn sign(&self, data: &[u8]) -> Signature {
-        self.secret_key.sign(data)
+    fn try_sign(&self, data: &[u8]) -> Result<Signature, SignerError> {
+        Ok(self.secret_key.try_sign(data)?)
     }
 
     fn compute_vrf_with_proof(&self, data: &[u8]) -> (crate::vrf::Value, crate::vrf::Proof) {
@@ -111,3 +143,75 @@ impl From<Arc<InMemorySigner>> for KeyFile {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::key_conversion::convert_public_key;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    /// Signer which holds the key elsewhere, like a remote signing service,
+    /// and can be disconnected.
+    struct MockSigner {
+        secret_key: SecretKey,
+        connected: AtomicBool,
+    }
+
+    impl Signer for MockSigner {
+        fn public_key(&self) -> PublicKey {
+            self.secret_key.public_key()
+        }
+
+        fn try_sign(&self, data: &[u8]) -> Result<Signature, SignerError> {
+            if !self.connected.load(Ordering::Relaxed) {
+                return Err(SignerError::Unavailable { error_message: "disconnected".to_string() });
+            }
+            Ok(self.secret_key.sign(data))
+        }
+
+        fn compute_vrf_with_proof(&self, data: &[u8]) -> (crate::vrf::Value, crate::vrf::Proof) {
+            self.secret_key.compute_vrf_with_proof(data)
+        }
+    }
+
+    fn sign_and_verify(signer: &dyn Signer, data: &[u8]) -> Result<bool, SignerError> {
+        let signature = signer.try_sign(data)?;
+        Ok(signer.verify(data, &signature))
+    }
+
+    #[test]
+    fn test_signers() {
+        let data = [7u8; 32];
+        let secret_key = SecretKey::from_seed(KeyType::ED25519, "test");
+        let mock = MockSigner { secret_key: secret_key.clone(), connected: AtomicBool::new(true) };
+        let in_memory =
+            InMemorySigner::from_secret_key("test".parse().unwrap(), secret_key.clone());
+        let signers: [&dyn Signer; 3] = [&secret_key, &mock, &in_memory];
+        for signer in signers {
+            assert_eq!(signer.public_key(), secret_key.public_key());
+            assert_eq!(signer.sign(&data), secret_key.sign(&data));
+            assert!(sign_and_verify(signer, &data).unwrap());
+            let (value, proof) = signer.compute_vrf_with_proof(&data);
+            let vrf_public_key =
+                convert_public_key(signer.public_key().unwrap_as_ed25519()).unwrap();
+            assert!(vrf_public_key.is_vrf_valid(&data, &value, &proof));
+        }
+
+        mock.connected.store(false, Ordering::Relaxed);
+        assert!(matches!(sign_and_verify(&mock, &data), Err(SignerError::Unavailable { .. })));
+
+        let secret_key = SecretKey::from_seed(KeyType::SECP256K1, "test");
+        assert!(matches!(
+            sign_and_verify(&secret_key, b"123"),
+            Err(SignerError::Signing(crate::SigningError::MessageLength { .. }))
+        ));
+        assert!(sign_and_verify(&secret_key, &data).unwrap());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_compute_vrf_with_proof_secp256k1() {
+        let secret_key = SecretKey::from_seed(KeyType::SECP256K1, "test");
+        Signer::compute_vrf_with_proof(&secret_key, b"123");
+    }
+}
@@ -4,7 +4,7 @@ use crate::signature::{
     ED25519PublicKey, ED25519SecretKey, KeyType, PublicKey, SecretKey,
     PRIVTAE_KEY_DEFAULT_RSA_KEY_BITS,
 };
-use crate::{InMemorySigner, Signature};
+use crate::InMemorySigner;
 use unc_account_id::AccountId;
 
 fn ed25519_key_pair_from_seed(seed: &str) -> ed25519_dalek::SigningKey {
@@ -33,6 +33,15 @@ fn rsa2048_secret_key_from_seed(seed: &str) -> rsa::RsaPrivateKey {
     rsa::RsaPrivateKey::new(&mut rng, PRIVTAE_KEY_DEFAULT_RSA_KEY_BITS).unwrap()
 }
 
+fn p256_secret_key_from_seed(seed: &str) -> p256::ecdsa::SigningKey {
+    let seed_bytes = seed.as_bytes();
+    let len = std::cmp::min(32, seed_bytes.len());
+    let mut seed: [u8; 32] = [b' '; 32];
+    seed[..len].copy_from_slice(&seed_bytes[..len]);
+    let mut rng = secp256k1::rand::rngs::StdRng::from_seed(seed);
+    p256::ecdsa::SigningKey::random(&mut rng)
+}
+
 impl PublicKey {
     pub fn from_seed(key_type: KeyType, seed: &str) -> Self {
         match key_type {
@@ -48,6 +57,7 @@ impl PublicKey {
                 let secret_key = SecretKey::RSA(Box::new(rsa2048_secret_key_from_seed(seed)));
                 PublicKey::RSA(Box::new(secret_key.public_key().unwrap_as_rsa2048().clone()))
             }
+            KeyType::P256 => SecretKey::P256(p256_secret_key_from_seed(seed)).public_key(),
         }
     }
 }
@@ -61,19 +71,35 @@ impl SecretKey {
             }
             KeyType::SECP256K1 => SecretKey::SECP256K1(secp256k1_secret_key_from_seed(seed)),
             KeyType::RSA2048 => SecretKey::RSA(Box::new(rsa2048_secret_key_from_seed(seed))),
+            KeyType::P256 => SecretKey::P256(p256_secret_key_from_seed(seed)),
         }
     }
 }
 
-const SIG: [u8; ed25519_dalek::SIGNATURE_LENGTH] = [0u8; ed25519_dalek::SIGNATURE_LENGTH];
+/// Precomputed RSA2048 secret keys returned by
+/// [`SecretKey::insecure_test_rsa2048_keys`].  These are public, never use
+/// them for anything but tests.
+#[cfg(any(test, feature = "test_features"))]
+const INSECURE_TEST_RSA2048_KEYS: [&str; 3] = [
+    "rsa2048:3Z2KzWG796gAE2zUz9Tuy3mBvA2sDfdSwPUqgwzS8pqnYdYrh2jY7SjnYwy2j3GASnQPxWDYzi2Wfd6iVy9Eb5f6qifdUWz2z3Rctu5UR8faaiUvdWe9XZm16EKjYfGE9NZFG5NCu4xYnyK9a1VD81LVEe3sW8MWuZYuNgHVsbCx6qchujfMmKbxy7r7pdAFFXzSKwqsMfoppr5V2RwoF8yapodHCCXRefZ3GVnB6SfzfXdiAUuP4d1T1sCfUqKtQffaKYyk4mKL9dorGpRzJorBTZmoVE4ooUQnaSoc8Li6zgmgifRerQdfn3hrdXUxj3yM4v41U1Dr39WuyjRGbFmbtgswtk7MGEboLFZExZ1xsrhcudNVnb1mCSX6JnBEtd5q5A5eKPRh91DoLLKnSgGTDv9v7YqJp8LQBAnNmz8EaFH2z3oLpknVWuQ9tcmALop6sMVAyXy9LwZyPuiDotdVeiSm2j2VHSMmkxkaQUZFDW4nv1BDNmGYrLyfW4jrvbV4v7qsWko6JqAP7iYAokquuu9nw93enZhbXiAMKS76VWAzJZgKpsE6PbrJXJfSmYcoWXSRNLxHZ2EbxdfrehK23NavsVTbR53Hc4iJsrVqMb7ywAdFSgBUnbVJerLRT3QEFJHbYguKNRaQKb7E6XcFinNZ7xSZTtnNcNUjiZ3gRCzu1gb6PSYwutQpGEtdQs2kGj6TyeJtwWtPnWsDBGZ71LTWuvUEwyv4qq2mEoBWUy3UjGY4aaJESzd8WT61utYAYfjfuZTpSKMzSWZ36TZwSVEFZ5BRuGYDzQVpfAL2pwsLABP2wLoPFzFMposURSqAJ7pc6oawehwtjJLTiQFw1kDVsnH95KdyELUbW4GuhR4RgS8xq9fMPAPyG59b2VXcnig2FvS9QkviJfm7vXm6ZtUKhvNmjxxuK2s9YMxc1kahZKMTt66PDNczgzN6cx5d3G6qTazjTsu1oWm3oM1gY6aC1ZwTMJYRHPPz4T8d6RJZ57LRLTsvs5D4RGbeEBw7PBkVKLpVGH14gJU6NCd79s64fM7b3JDoyCQ6gzR92DSUY8mJa9nP6zA3q7ULdzurhGcxsGKCzh6Fr1YzoPMpmF6ftSEfL5VwoUyMqp1hLMXX1ZrQf4tWyC2aKeoNR2AdkzGQnBG8t252Q3xrqnuGCzaZJC9hsytBrxsguwVCTufjQ6kuoprLACvFrTE3Hm9BqtYD98q89k7Cjb87Jhsk9apkvrcFuor2TU27ebHoQVpn74nKGaJSj1YcG2rZyYG8WQb8YKzJmbkSN55W8vXPKBu7VyDpAuYdaDzuHHdeqMrYe4p4oDrXitUBCccFsQrcMQokpvo1Z1jya6GXPr95HXcEBzVjwa3Ej2XUoTRHogqKYQ7vdQfsR6Pe96RUtZprrtKtoFWeN7rf3GxJZ7DZsGRSrBL5FXtF6FvquoS31tbKFJY5Avnk8ifz76M3wJjNpdJeWsFn9aiprPsY6vdv8j9oDGWs841qWVZRLn1Q58DMCNZaknatb1KHbS3CmobDCGfBrcyJ5dVunk6yjTKUHrUqsKZZkjLB61eq4PJKj4mfFuX74qFv483VpvYUA7eUvTB8gMYQhrC1QJ9LLEPXWCTcTUmUN4fQEB1opLMx9WdwVP7cwXSuH3qeW",
+    "rsa2048:CGLrpyEbjWmqGRdPfuXXmAs3YucVQkXY8DATQqR5ogPh22efVGzSCnNozehU6ekC4SyymMAQopS9X5UwYgqUu39rUADUq1KLc6p1mig9wAFP71xBCPVY8jz7w3Nk1NeCVMmo3JJTb18toeGPsiTPCdyv2Ug28fMNJr6zZKovpngrAt1PKEbG336TcvJaJFxcxBAabVza9Yb9wnjovAxpDeP54jUyr3CdoAw3bE2vNxk8dmZS63nhUnzxDuMPewyPnzj6EQBX7F4EYCBNocSJ4hV3QkDJSS3X6JVkdUD6fM1ndjhD5eUzaEbkuUW4jnwHB6xWwg1PEKxs3wdwQBDTxUeqbLJJXsN4HVFH4mANWCprDmxHYS7F44jJi6QbJkCsLjdxzVbNb5tsqvPxeMHAd5TpW6EL1nF3FeyarjfcezKkn7C19tEJaVc347AqEcdo6fw171BwSH3ehcjy1eLbmrSSUg8FohiFWJ4ByfTVpgun6AGJPFUikeRKQfFhUTNBndck9thfKRTJNdpNvSQvEwrYQ1q7X1Je7tEKsJax8e71oHMGhmySDoHn6GHg9MxsuQtAxtFPqMTqXTsLwgHALGzkHUKvqAEaDAh56Cp3nGjNiuvdq5A7jskJt7hTu4FNCZMu5pEV9vqkAMdKuAVUFNAdaTADsXp9pP7796GNrdwD1rnkEGeEieTgHnQD1YwLr3nArZwML6STqjq5yb88ZZzuFS9NFwuXKmGgnrLjte9Jz2Nx5fU54Q8CUi7zHYL9LDqfDY8w5aSaDzF5Nsn8gpH4RmMbfoYb6D8cjRoZsQqipEoVoGJykwNA8DXBLokcmTMnTNmGkB6SjMNKWde6rEuXaoJmqYV5gVRV3wW87WUXGQtMiDeG2HU7ALxDP4ACbg2wxX1oZzvYFyGtTkE7Qg7Y4f28UCbDUF7sSyxXifT9sGTvMNZ3zkAqgRdch1aiGsXpfQpGJkvH97b8fxg4U9y2QwYTViXVoWL6Eov2p1j2wxPqoPsk2JyojJMJV4Sy7GuKRytDd3QNqv1kRp6Cr2pcWJwRT18g9cJQ5FzSkPvr95wUGpd1NSziQ2RPEY1XoVXHfKShpfCxwxQ8nDAZPuvn6JTAXUPLwTYUj5mAZ8iftPBbmWubmGNXCruzNXPqC7Dxu3Edi25n9g6i6YKDfxV26VRPg2LHJsVXxJsb83wYXjpqSvHDRs8PkiB6AeeszLyEWAZfAEDnnaJmscGaSgm2TULDDPNseMDNHE9JoBGXpFFqwWofM71pbmN1XtAQb5ax3LrtS4RzzTSj9Dre7CwCEEU7A2EC2RFPUWHUX5xLKj7KR4DpVB63ezELQfdBRuDqaUk4WxHS25A7c7aWES1vKLj18RjkTyfHhcMsQaonVaTcHgdAH8x66nLsWac7Lv9FJ3yz5zFNP9Fwc9V2dJ86KdRKFKHb9xhzW5LLP5Xg8vARVyaYLS5Zp1G4S8pxXtavr8AfTVSMT6smx44FR3GTcWqFyog8bBMqdfyrmnvNZSDYnMw1derFkCJBPWuxCi8m6JpiwKqxY2ovGziy2rHwaYdzSYB7z6Gq9fBuZgn9WwApoiMW3URXpHbDU1voxooPH3wfsKodzw53e1hMjFHYF3FvfakRimLf91MZpPtmdTcAPyVzj1H26wh3EL",
+    "rsa2048:CGLrpyEbjWmqGRdPfuXXmAs3YucVQkXY8DATQqR5ogPh22efVGzRrbyVQPXGubBkNVZ3TfMy4JZUf616D2P4RfRoc1XLrr7W4rZAopAaK3Tvhy8NfbnH79gunGUK1Bbi8peHxYFXcEDU1jUf7WUDfhhytL373G4r9Rg1WpmxanpbRWHFin6aziC7YokvA1eqCZtG7YGJfJm6qqWXQPpzpsdk13FsHeTqkG6kDqovMMexqQxSqaPfx1XJFrNegTRnow5vywA8nGBSFkeqw62DE5iRkce2SK8N3KCfRnwwS6AWvuA8JUAXgySRAAuWZXCDy6DdDA6SUsSTULgmvYZf3U7KNmKvvPFMx9ADx12aFzPPkQz5L8cJH69MhTfJ4sj9zXsSDFoa1maCAivNZhchPaqx4yF472HCDnSzTgajiF9Lm9XmRVRfuGDq3gEgPcy9f6bFEhg6Lk3J33XjVEXuL3v9ccvT2citYftU9BUg8nAhrnG15NWysgHMy3YzZEj8w88RwjyDgGphhiDyzdXBjKXigFQ1CJy2S7xfWGXzcqPqHPyd8KVqLNv4dkaeFNgMD3RAehQsQg45WYhFrKa1z1tugnNa96Hui8YyArhzebkkAC3rvePG7jaK7RT2jxbJzrQRinq9D5twFcgCiEN6n3hDx2xAaZyneqC8MpJiSmQNnQNH3LQJ1j9pB3SyzoMLTmMvM27gjd4bQgwNjAGwZ2cp9mX21YAfqWFq2n4w3c5JWXZPKSFTwnvy4qQ9tBCyQVKoMexhVbo14bWjff56Es7mYhcqgKBbiPYB2812Rs5oVtPdWKqguXhoNSrkE3U3wRYuT8R77k2rGAjHcJAGozfuP5AdF6HBAFRL1vA8ERf4vCmmsCRBC8gA29eLpymPE1tfyp3oahac45wGiVkcr85gecRSMbC7btDztWPzifLPyJ5JtrUfvMSb81QsVYoQKnCb4v89Q1Vm6MWrBDUmMUBLLbK4ZcfnhoFyNTj1qy97bpJ4ZYskJ5T79vhA966xcu7ivxKAHwHafqxNGj6jpBnD83sdXs8tBYKVtJ4kiDVr77yqQH6HDoeD2tVgyAGgZUrAuCiddQ3yyGwVkAqArJmtEJsEe1gLko2P2FQsAz3ndhE7GsUuZX1unJzBxLr1AgnQjp3RSsQgg39stBPYrVpfvGL7RpXpwRasSJx1H6Xe6L7SvRq4y6cXJphMD6Q65rqwTkdob6WMBK5ZaTKn5rmdeVvKU5ePe6TgJ3kap5sp1J9kHEgnhH4SMnmTgPGjwP7hYCg3w3S9cqDHTcJup5JN29FUiM6a7soLqQqKpixF3aBG78Xp1ofYrcx4DobgUfWbunGM4pMuzSR6w1m54ReAPsf4JPSEu5tYWrCMRpJaKqHok9SggNxthdQmAosX2sMJCFs8UJYjuuobFE2qVWWGyHrX49vLGFETMPnCpZqxz4KTAikWq5CQxVwCxU6M2ouwSLxq5dmG9TKsKi31d4fPujBeSHV9zVHzy3u9S2apWeiTt1H6X6YtHGzhwuzZc5cLYNcBMQ9Axn4MZP6mUdvSH5zF3SETFwcYppvBG8WvvimZJBwoSdkmEGBnjqhXotGb6vPAqcMFHbDLRWBfFn64Edztiv6nd8LGe6rmSXDEgPE8XqFo5ystA2y9Nc",
+];
 
-impl Signature {
-    /// Empty signature that doesn't correspond to anything.
-    pub fn empty(key_type: KeyType) -> Self {
-        match key_type {
-            KeyType::ED25519 => Signature::ED25519(ed25519_dalek::Signature::from_bytes(&SIG)),
-            _ => unimplemented!(),
-        }
+#[cfg(any(test, feature = "test_features"))]
+impl SecretKey {
+    /// Returns a few fixed RSA2048 secret keys for tests which need RSA keys
+    /// but don't want to pay for RSA key generation (which takes hundreds of
+    /// milliseconds per key, see [`SecretKey::from_seed`]).
+    ///
+    /// **INSECURE: FOR TESTS ONLY.**  The keys are hardcoded in the public
+    /// source code so anyone can sign with them.  They must never be used in
+    /// production.
+    pub fn insecure_test_rsa2048_keys() -> Vec<SecretKey> {
+        INSECURE_TEST_RSA2048_KEYS
+            .iter()
+            .map(|key| key.parse().expect("precomputed RSA key is valid"))
+            .collect()
     }
 }
 
@@ -83,3 +109,20 @@ impl InMemorySigner {
         Self { account_id, public_key: secret_key.public_key(), secret_key }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insecure_test_rsa2048_keys() {
+        let keys = SecretKey::insecure_test_rsa2048_keys();
+        assert_eq!(keys.len(), INSECURE_TEST_RSA2048_KEYS.len());
+        let data = [7u8; 32];
+        for (i, key) in keys.iter().enumerate() {
+            assert_eq!(key.key_type() as u8, KeyType::RSA2048 as u8);
+            assert!(key.sign(&data).verify(&data, &key.public_key()));
+            assert!(keys[..i].iter().all(|other| other != key));
+        }
+    }
+}
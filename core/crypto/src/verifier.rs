@@ -0,0 +1,119 @@
+use crate::{Digest32, KeyType, PublicKey, Signature};
+use sha2::{Digest, Sha256, Sha512};
+
+enum Hasher {
+    Sha256(Sha256),
+    Sha512(Sha512),
+}
+
+/// Verifies a signature over a message which is fed in chunks, so that the
+/// whole message never has to be held in memory.
+///
+/// The message is digested as it comes in, hence what gets verified depends
+/// on the key type:
+/// - ED25519: an Ed25519ph signature without context, i.e. one made over the
+///   SHA-512 prehash of the message with
+///   `ed25519_dalek::SigningKey::sign_prehashed`;
+/// - SECP256K1 and RSA2048: a signature made with [`crate::SecretKey::sign`]
+///   over the SHA-256 digest of the message;
+/// - P256: same as [`Signature::verify`] over the whole message.
+pub struct SignatureVerifier {
+    key_type: KeyType,
+    hasher: Hasher,
+}
+
+impl SignatureVerifier {
+    pub fn new(key_type: KeyType) -> Self {
+        let hasher = match key_type {
+            KeyType::ED25519 => Hasher::Sha512(Sha512::new()),
+            KeyType::SECP256K1 | KeyType::RSA2048 | KeyType::P256 => Hasher::Sha256(Sha256::new()),
+        };
+        Self { key_type, hasher }
+    }
+
+    /// Feeds the next chunk of the message.
+    pub fn update(&mut self, data: &[u8]) {
+        match &mut self.hasher {
+            Hasher::Sha256(hasher) => hasher.update(data),
+            Hasher::Sha512(hasher) => hasher.update(data),
+        }
+    }
+
+    /// Verifies `signature` over the message fed so far with `public_key`.
+    /// Returns `false` if the signature isn't of the key type this verifier
+    /// was created for.
+    pub fn finalize(self, signature: &Signature, public_key: &PublicKey) -> bool {
+        if signature.key_type() as u8 != self.key_type as u8 || public_key.is_zero() {
+            return false;
+        }
+        match (self.hasher, signature, public_key) {
+            (Hasher::Sha512(hasher), Signature::ED25519(signature), PublicKey::ED25519(key)) => {
+                ed25519_dalek::VerifyingKey::try_from(key)
+                    .map_or(false, |key| key.verify_prehashed(hasher, None, signature).is_ok())
+            }
+            (Hasher::Sha256(hasher), _, _) => {
+                signature.verify_digest(Digest32(hasher.finalize().into()), public_key)
+            }
+            (Hasher::Sha512(_), _, _) => false,
+        }
+    }
+}
+
+impl std::io::Write for SignatureVerifier {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SecretKey;
+
+    fn verify_chunked(key_type: KeyType, data: &[u8], sig: &Signature, pk: &PublicKey) -> bool {
+        let mut verifier = SignatureVerifier::new(key_type);
+        for chunk in data.chunks(1000) {
+            verifier.update(chunk);
+        }
+        verifier.finalize(sig, pk)
+    }
+
+    #[test]
+    fn test_streaming_verify() {
+        let data: Vec<u8> = (0..100_000u32).map(|i| (i % 251) as u8).collect();
+        let digest = Sha256::digest(&data);
+
+        let sk = SecretKey::from_seed(KeyType::ED25519, "test");
+        let SecretKey::ED25519(secret_key) = &sk else { unreachable!() };
+        let signing_key = ed25519_dalek::SigningKey::from(secret_key);
+        let ed25519_signature = Signature::ED25519(
+            signing_key.sign_prehashed(Sha512::new().chain_update(&data), None).unwrap(),
+        );
+        let mut verifier = SignatureVerifier::new(KeyType::ED25519);
+        std::io::copy(&mut data.as_slice(), &mut verifier).unwrap();
+        assert!(verifier.finalize(&ed25519_signature, &sk.public_key()));
+        assert!(!verify_chunked(
+            KeyType::ED25519,
+            &data[1..],
+            &ed25519_signature,
+            &sk.public_key()
+        ));
+
+        for key_type in [KeyType::SECP256K1, KeyType::RSA2048, KeyType::P256] {
+            let sk = SecretKey::from_seed(key_type, "test");
+            let pk = sk.public_key();
+            let signature = match key_type {
+                KeyType::P256 => sk.sign(&data),
+                _ => sk.sign(&digest),
+            };
+            assert!(verify_chunked(key_type, &data, &signature, &pk), "{key_type}");
+            assert!(!verify_chunked(key_type, &data[1..], &signature, &pk), "{key_type}");
+            assert!(!verify_chunked(KeyType::ED25519, &data, &signature, &pk), "{key_type}");
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{KeyType, PublicKey, Signature};
+
+/// Amount of gas charged for an operation.
+///
+/// Same as `unc_primitives_core::types::Gas`, duplicated here so that this
+/// crate doesn't have to depend on the primitives.
+pub type Gas = u64;
+
+/// Gas charged for verifying a signature of a given key type.
+///
+/// Total cost of a verification is `base + byte * data.len()`, mirroring how
+/// the `ed25519_verify_base`/`ed25519_verify_byte` host function costs are
+/// computed by the runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct VerifyCost {
+    pub base: Gas,
+    pub byte: Gas,
+}
+
+impl VerifyCost {
+    fn compute(&self, data_len: usize) -> Gas {
+        let data_len = u64::try_from(data_len).unwrap_or(u64::MAX);
+        self.base.saturating_add(self.byte.saturating_mul(data_len))
+    }
+}
+
+/// Per key type verification costs used by [`Signature::verify_metered`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct VerifyCostTable {
+    pub ed25519: VerifyCost,
+    pub secp256k1: VerifyCost,
+    pub rsa2048: VerifyCost,
+    pub p256: VerifyCost,
+}
+
+impl VerifyCostTable {
+    pub fn cost(&self, key_type: KeyType) -> &VerifyCost {
+        match key_type {
+            KeyType::ED25519 => &self.ed25519,
+            KeyType::SECP256K1 => &self.secp256k1,
+            KeyType::RSA2048 => &self.rsa2048,
+            KeyType::P256 => &self.p256,
+        }
+    }
+}
+
+impl Signature {
+    /// Verifies the signature like [`Signature::verify`] and returns the gas
+    /// that should be charged for it according to `cost_table`.
+    ///
+    /// The gas is computed from the signature's key type and the length of
+    /// `data`, and is charged regardless of whether verification succeeded.
+    pub fn verify_metered(
+        &self,
+        data: &[u8],
+        public_key: &PublicKey,
+        cost_table: &VerifyCostTable,
+    ) -> (bool, Gas) {
+        let gas = cost_table.cost(self.key_type()).compute(data.len());
+        (self.verify(data, public_key), gas)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SecretKey;
+
+    const COST_TABLE: VerifyCostTable = VerifyCostTable {
+        ed25519: VerifyCost { base: 1_513_656_750, byte: 7_157_035 },
+        secp256k1: VerifyCost { base: 3_365_369_625, byte: 0 },
+        rsa2048: VerifyCost { base: 6_000_000_000, byte: 10_000_000 },
+        p256: VerifyCost { base: 3_500_000_000, byte: 7_000_000 },
+    };
+
+    #[test]
+    fn test_verify_metered() {
+        use sha2::Digest;
+        let data = sha2::Sha256::digest(b"123").to_vec();
+
+        let sk = SecretKey::from_seed(KeyType::ED25519, "test");
+        let (ok, ed25519_gas) = sk.sign(&data).verify_metered(&data, &sk.public_key(), &COST_TABLE);
+        assert!(ok);
+        assert_eq!(ed25519_gas, 1_513_656_750 + 7_157_035 * 32);
+
+        let sk = SecretKey::from_seed(KeyType::RSA2048, "test");
+        let (ok, rsa_gas) = sk.sign(&data).verify_metered(&data, &sk.public_key(), &COST_TABLE);
+        assert!(ok);
+        assert!(rsa_gas > ed25519_gas);
+
+        // Failed verification is still charged.
+        let other = PublicKey::empty(KeyType::ED25519);
+        assert_eq!(sk.sign(&data).verify_metered(&data, &other, &COST_TABLE), (false, rsa_gas));
+    }
+}
@@ -121,6 +121,9 @@ pub enum ProtocolFeature {
     /// Enables chunk validation which is introduced with stateless validation.
     ChunkValidation,
     EthAccounts,
+    /// Allow P-256 (secp256r1) keys in transactions, access keys and delegate
+    /// actions.
+    P256Keys,
 }
 
 impl ProtocolFeature {
@@ -177,6 +180,7 @@ impl ProtocolFeature {
             ProtocolFeature::RejectBlocksWithOutdatedProtocolVersions => 132,
             ProtocolFeature::ChunkValidation => 137,
             ProtocolFeature::EthAccounts => 138,
+            ProtocolFeature::P256Keys => 139,
         }
     }
 }
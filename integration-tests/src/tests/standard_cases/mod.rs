@@ -339,6 +339,7 @@ pub fn transfer_tokens_to_implicit_account(node: impl Node, public_key: PublicKe
         KeyType::ED25519 => derive_unc_account_id(public_key.unwrap_as_ed25519()),
         KeyType::SECP256K1 => derive_eth_implicit_account_id(public_key.unwrap_as_secp256k1()),
         KeyType::RSA2048 => panic!("RSA keys not supported"),
+        KeyType::P256 => panic!("P256 keys not supported"),
     };
 
     let transfer_cost = match receiver_id.get_account_type() {
@@ -412,6 +413,7 @@ pub fn trying_to_create_implicit_account(node: impl Node, public_key: PublicKey)
         KeyType::ED25519 => derive_unc_account_id(public_key.unwrap_as_ed25519()),
         KeyType::SECP256K1 => derive_eth_implicit_account_id(public_key.unwrap_as_secp256k1()),
         KeyType::RSA2048 => panic!("RSA keys not supported"),
+        KeyType::P256 => panic!("P256 keys not supported"),
     };
 
     let transaction_result = node_user
@@ -2,6 +2,7 @@ use crate::config::{total_prepaid_gas, tx_cost, TransactionCost};
 use crate::unc_primitives::account::Account;
 use crate::VerificationResult;
 use unc_crypto::key_conversion::{is_valid_challenge_key, is_valid_staking_key};
+use unc_crypto::{KeyType, PublicKey};
 use unc_parameters::RuntimeConfig;
 use unc_primitives::account::AccessKeyPermission;
 use unc_primitives::action::delegate::SignedDelegateAction;
@@ -93,6 +94,9 @@ pub fn validate_transaction(
     let transaction = &signed_transaction.transaction;
     let signer_id = &transaction.signer_id;
 
+    validate_key_type(&transaction.public_key, current_protocol_version)
+        .map_err(InvalidTxError::ActionsValidation)?;
+
     if verify_signature
         && !signed_transaction
             .signature
@@ -399,7 +403,7 @@ pub fn validate_action(
         Action::FunctionCall(a) => validate_function_call_action(limit_config, a),
         Action::Transfer(_) => Ok(()),
         Action::Pledge(a) => validate_pledge_action(a),
-        Action::AddKey(a) => validate_add_key_action(limit_config, a),
+        Action::AddKey(a) => validate_add_key_action(limit_config, a, current_protocol_version),
         Action::DeleteKey(_) => Ok(()),
         Action::DeleteAccount(a) => validate_delete_action(a),
         Action::Delegate(a) => validate_delegate_action(limit_config, a, current_protocol_version),
@@ -413,11 +417,32 @@ fn validate_delegate_action(
     signed_delegate_action: &SignedDelegateAction,
     current_protocol_version: ProtocolVersion,
 ) -> Result<(), ActionsValidationError> {
+    validate_key_type(
+        &signed_delegate_action.delegate_action.public_key,
+        current_protocol_version,
+    )?;
     let actions = signed_delegate_action.delegate_action.get_actions();
     validate_actions(limit_config, &actions, current_protocol_version)?;
     Ok(())
 }
 
+/// Checks that keys of the type of `public_key` may be used at the given
+/// protocol version.
+fn validate_key_type(
+    public_key: &PublicKey,
+    current_protocol_version: ProtocolVersion,
+) -> Result<(), ActionsValidationError> {
+    if let KeyType::P256 = public_key.key_type() {
+        if !checked_feature!("stable", P256Keys, current_protocol_version) {
+            return Err(ActionsValidationError::UnsupportedProtocolFeature {
+                protocol_feature: String::from("P256Keys"),
+                version: ProtocolFeature::P256Keys.protocol_version(),
+            });
+        }
+    }
+    Ok(())
+}
+
 /// Validates `DeployContractAction`. Checks that the given contract size doesn't exceed the limit.
 fn validate_deploy_contract_action(
     limit_config: &LimitConfig,
@@ -471,13 +496,15 @@ fn validate_pledge_action(action: &PledgeAction) -> Result<(), ActionsValidation
     Ok(())
 }
 
-/// Validates `AddKeyAction`. If the access key permission is `FunctionCall`, checks that the
-/// total number of bytes of the method names doesn't exceed the limit and
-/// every method name length doesn't exceed the limit.
+/// Validates `AddKeyAction`. Checks that the key type is enabled at the protocol version. If
+/// the access key permission is `FunctionCall`, checks that the total number of bytes of the
+/// method names doesn't exceed the limit and every method name length doesn't exceed the limit.
 fn validate_add_key_action(
     limit_config: &LimitConfig,
     action: &AddKeyAction,
+    current_protocol_version: ProtocolVersion,
 ) -> Result<(), ActionsValidationError> {
+    validate_key_type(&action.public_key, current_protocol_version)?;
     if let AccessKeyPermission::FunctionCall(fc) = &action.access_key.permission {
         // Check whether `receiver_id` is a valid account_id. Historically, we
         // allowed arbitrary strings there!
@@ -1494,6 +1521,32 @@ mod tests {
         .expect("valid transaction");
     }
 
+    #[test]
+    fn test_validate_transaction_p256_key() {
+        let config = RuntimeConfig::test();
+        let signer = InMemorySigner::from_seed(alice_account(), KeyType::P256, "test");
+        let transaction = SignedTransaction::send_money(
+            1,
+            alice_account(),
+            bob_account(),
+            &signer,
+            100,
+            CryptoHash::default(),
+        );
+        let version = ProtocolFeature::P256Keys.protocol_version();
+        validate_transaction(&config, 100, &transaction, true, version).expect("valid transaction");
+        assert_eq!(
+            validate_transaction(&config, 100, &transaction, true, version - 1)
+                .expect_err("expected an error"),
+            RuntimeError::InvalidTxError(InvalidTxError::ActionsValidation(
+                ActionsValidationError::UnsupportedProtocolFeature {
+                    protocol_feature: String::from("P256Keys"),
+                    version,
+                }
+            )),
+        );
+    }
+
     // Receipts
 
     #[test]
@@ -1827,6 +1880,23 @@ mod tests {
         .expect("valid action");
     }
 
+    #[test]
+    fn test_validate_action_add_key_p256() {
+        let action = Action::AddKey(Box::new(AddKeyAction {
+            public_key: PublicKey::from_seed(KeyType::P256, "test"),
+            access_key: AccessKey::full_access(),
+        }));
+        let version = ProtocolFeature::P256Keys.protocol_version();
+        validate_action(&test_limit_config(), &action, version).expect("valid action");
+        assert_eq!(
+            validate_action(&test_limit_config(), &action, version - 1),
+            Err(ActionsValidationError::UnsupportedProtocolFeature {
+                protocol_feature: String::from("P256Keys"),
+                version,
+            }),
+        );
+    }
+
     #[test]
     fn test_validate_action_valid_delete_key() {
         validate_action(
@@ -1897,6 +1967,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_validate_delegate_action_p256_key() {
+        let action = Action::Delegate(Box::new(SignedDelegateAction {
+            delegate_action: DelegateAction {
+                sender_id: "bob.test.unc".parse().unwrap(),
+                receiver_id: "token.test.unc".parse().unwrap(),
+                actions: vec![NonDelegateAction::try_from(Action::CreateAccount(
+                    CreateAccountAction {},
+                ))
+                .unwrap()],
+                nonce: 19000001,
+                max_block_height: 57,
+                public_key: PublicKey::from_seed(KeyType::P256, "test"),
+            },
+            signature: Signature::default(),
+        }));
+        let version = ProtocolFeature::P256Keys.protocol_version();
+        validate_action(&test_limit_config(), &action, version).expect("valid action");
+        assert_eq!(
+            validate_action(&test_limit_config(), &action, version - 1),
+            Err(ActionsValidationError::UnsupportedProtocolFeature {
+                protocol_feature: String::from("P256Keys"),
+                version,
+            }),
+        );
+    }
+
     #[test]
     fn test_truncate_string() {
         fn check(input: &str, limit: usize, want: &str) {
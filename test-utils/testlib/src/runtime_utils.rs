@@ -1,8 +1,8 @@
 use unc_chain_configs::Genesis;
-use unc_crypto::PublicKey;
+use unc_crypto::{KeyType, PublicKey, SecretKey};
 use unc_primitives::account::{AccessKey, Account};
 use unc_primitives::hash::hash;
-use unc_primitives::state_record::StateRecord;
+use unc_primitives::state_record::{state_record_to_account_id, StateRecord};
 use unc_primitives::types::{AccountId, Balance};
 
 pub fn alice_account() -> AccountId {
@@ -22,6 +22,38 @@ pub fn x_dot_y_dot_z_account() -> AccountId {
     "x.y.z".parse().unwrap()
 }
 
+/// Deterministic ED25519 key of a test account, seeded by the account id.
+fn account_key(account_id: &AccountId) -> SecretKey {
+    SecretKey::from_seed(KeyType::ED25519, account_id.as_str())
+}
+
+pub fn alice_key() -> SecretKey {
+    account_key(&alice_account())
+}
+pub fn bob_key() -> SecretKey {
+    account_key(&bob_account())
+}
+/// Key of [`carol_account`], seeded by its actual id `carl`.
+pub fn carol_key() -> SecretKey {
+    account_key(&carol_account())
+}
+pub fn dan_key() -> SecretKey {
+    account_key(&dan_account())
+}
+
+pub fn alice_pk() -> PublicKey {
+    alice_key().public_key()
+}
+pub fn bob_pk() -> PublicKey {
+    bob_key().public_key()
+}
+pub fn carol_pk() -> PublicKey {
+    carol_key().public_key()
+}
+pub fn dan_pk() -> PublicKey {
+    dan_key().public_key()
+}
+
 /// Pre-deploy in genesis the standard test contract for a given account.
 ///
 /// This contract contains various functions useful for testing and its code is available in
@@ -49,9 +81,67 @@ pub fn add_contract(genesis: &mut Genesis, account_id: &AccountId, code: Vec<u8>
             account: Account::new(0, 0, 0, hash, 0),
         });
     }
+    // Redeploying replaces the previous contract, same as the code hash above.
+    records.retain(|record| match record {
+        StateRecord::Contract { account_id: record_account_id, .. } => {
+            record_account_id != account_id
+        }
+        _ => true,
+    });
     records.push(StateRecord::Contract { account_id: account_id.clone(), code });
 }
 
+/// Add an account with a specified balance and no access keys to the genesis state records.
+pub fn add_account(genesis: &mut Genesis, account_id: AccountId, balance: Balance) {
+    genesis.force_read_records().as_mut().push(StateRecord::Account {
+        account_id,
+        account: Account::new(balance, 0, 0, Default::default(), 0),
+    });
+}
+
+/// Set the balance of an account in the genesis state records, adding the account if it's missing.
+///
+/// Unlike [`add_account`] this never produces a duplicate account record.  Other fields of an
+/// existing account (e.g. code hash of a deployed contract) are kept.
+pub fn upsert_account(genesis: &mut Genesis, account_id: AccountId, balance: Balance) {
+    let records = genesis.force_read_records().as_mut();
+    let existing = records.iter_mut().find_map(|record| match record {
+        StateRecord::Account { account_id: record_account_id, account }
+            if *record_account_id == account_id =>
+        {
+            Some(account)
+        }
+        _ => None,
+    });
+    match existing {
+        Some(account) => account.set_amount(balance),
+        None => add_account(genesis, account_id, balance),
+    }
+}
+
+/// Remove all genesis state records of an account: the account itself, its access keys,
+/// contract code, data and postponed receipts.
+pub fn remove_account(genesis: &mut Genesis, account_id: &AccountId) {
+    genesis
+        .force_read_records()
+        .as_mut()
+        .retain(|record| state_record_to_account_id(record) != account_id);
+}
+
+/// Add an access key of an account to the genesis state records.
+pub fn add_access_key(
+    genesis: &mut Genesis,
+    account_id: AccountId,
+    public_key: PublicKey,
+    access_key: AccessKey,
+) {
+    genesis.force_read_records().as_mut().push(StateRecord::AccessKey {
+        account_id,
+        public_key,
+        access_key,
+    });
+}
+
 /// Add an account with a specified access key & balance to the genesis state records.
 pub fn add_account_with_access_key(
     genesis: &mut Genesis,
@@ -60,10 +150,322 @@ pub fn add_account_with_access_key(
     public_key: PublicKey,
     access_key: AccessKey,
 ) {
-    let records = genesis.force_read_records().as_mut();
-    records.push(StateRecord::Account {
-        account_id: account_id.clone(),
-        account: Account::new(balance, 0, 0, Default::default(), 0),
-    });
-    records.push(StateRecord::AccessKey { account_id, public_key, access_key });
+    add_account_with_access_keys(genesis, account_id, balance, vec![(public_key, access_key)]);
+}
+
+/// Add an account with a specified balance and several access keys to the genesis state records.
+pub fn add_account_with_access_keys(
+    genesis: &mut Genesis,
+    account_id: AccountId,
+    balance: Balance,
+    keys: Vec<(PublicKey, AccessKey)>,
+) {
+    add_account(genesis, account_id.clone(), balance);
+    for (public_key, access_key) in keys {
+        add_access_key(genesis, account_id.clone(), public_key, access_key);
+    }
+}
+
+/// Add a fresh account with a random `acc<N>.test` id and a full access key of a random ED25519
+/// key to the genesis state records.
+///
+/// Returns the account id together with the secret key so that the caller can sign transactions
+/// on behalf of the account.
+pub fn random_account_with_key(genesis: &mut Genesis, balance: Balance) -> (AccountId, SecretKey) {
+    let account_id: AccountId = format!("acc{}.test", rand::random::<u64>()).parse().unwrap();
+    let secret_key = SecretKey::from_random(KeyType::ED25519);
+    add_account_with_access_key(
+        genesis,
+        account_id.clone(),
+        balance,
+        secret_key.public_key(),
+        AccessKey::full_access(),
+    );
+    (account_id, secret_key)
+}
+
+/// Find the account record of the given account in the genesis state records.
+///
+/// Takes `&mut Genesis` only to load the records into memory, they are not modified.
+pub fn find_account<'a>(genesis: &'a mut Genesis, account_id: &AccountId) -> Option<&'a Account> {
+    genesis.force_read_records().0.iter().find_map(|record| match record {
+        StateRecord::Account { account_id: record_account_id, account }
+            if record_account_id == account_id =>
+        {
+            Some(account)
+        }
+        _ => None,
+    })
+}
+
+/// Find an access key of the given account in the genesis state records.
+///
+/// Takes `&mut Genesis` only to load the records into memory, they are not modified.
+pub fn find_access_key<'a>(
+    genesis: &'a mut Genesis,
+    account_id: &AccountId,
+    public_key: &PublicKey,
+) -> Option<&'a AccessKey> {
+    genesis.force_read_records().0.iter().find_map(|record| match record {
+        StateRecord::AccessKey {
+            account_id: record_account_id,
+            public_key: record_key,
+            access_key,
+        } if record_account_id == account_id && record_key == public_key => Some(access_key),
+        _ => None,
+    })
+}
+
+/// Balance of the given account in the genesis state records.
+pub fn account_balance(genesis: &mut Genesis, account_id: &AccountId) -> Option<Balance> {
+    find_account(genesis, account_id).map(Account::amount)
+}
+
+/// Chainable wrapper around the functions above for setting up genesis
+/// records in tests:
+///
+/// ```ignore
+/// let genesis = GenesisBuilder::new(genesis)
+///     .account(alice_account(), 1_000)
+///     .access_key(alice_account(), alice_pk(), AccessKey::full_access())
+///     .contract(alice_account(), code)
+///     .build();
+/// ```
+pub struct GenesisBuilder {
+    genesis: Genesis,
+}
+
+impl GenesisBuilder {
+    /// Starts from the given genesis keeping its config and records.
+    pub fn new(genesis: Genesis) -> Self {
+        Self { genesis }
+    }
+
+    /// See [`add_account`].
+    pub fn account(mut self, account_id: AccountId, balance: Balance) -> Self {
+        add_account(&mut self.genesis, account_id, balance);
+        self
+    }
+
+    /// See [`add_access_key`].
+    pub fn access_key(
+        mut self,
+        account_id: AccountId,
+        public_key: PublicKey,
+        access_key: AccessKey,
+    ) -> Self {
+        add_access_key(&mut self.genesis, account_id, public_key, access_key);
+        self
+    }
+
+    /// See [`add_contract`].
+    pub fn contract(mut self, account_id: AccountId, code: Vec<u8>) -> Self {
+        add_contract(&mut self.genesis, &account_id, code);
+        self
+    }
+
+    pub fn build(self) -> Genesis {
+        self.genesis
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use unc_primitives::account::{AccessKeyPermission, FunctionCallPermission};
+
+    #[test]
+    fn test_account_keys() {
+        assert_eq!(alice_key(), SecretKey::from_seed(KeyType::ED25519, "alice"));
+        assert_eq!(carol_key(), SecretKey::from_seed(KeyType::ED25519, "carl"));
+        assert_eq!(bob_pk(), PublicKey::from_seed(KeyType::ED25519, "bob"));
+        assert_ne!(alice_pk(), dan_pk());
+    }
+
+    #[test]
+    fn test_add_account_with_access_keys() {
+        let mut genesis = Genesis::default();
+        let function_call_key = AccessKey {
+            nonce: 0,
+            permission: AccessKeyPermission::FunctionCall(FunctionCallPermission {
+                allowance: None,
+                receiver_id: bob_account().to_string(),
+                method_names: vec![],
+            }),
+        };
+        add_account_with_access_keys(
+            &mut genesis,
+            alice_account(),
+            100,
+            vec![
+                (alice_pk(), AccessKey::full_access()),
+                (bob_pk(), function_call_key.clone()),
+                (carol_pk(), function_call_key),
+            ],
+        );
+        let records = &genesis.force_read_records().0;
+
+        let balances: Vec<Balance> = records
+            .iter()
+            .filter_map(|record| match record {
+                StateRecord::Account { account, .. } => Some(account.amount()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(balances, [100]);
+        let keys: Vec<&PublicKey> = records
+            .iter()
+            .filter_map(|record| match record {
+                StateRecord::AccessKey { account_id, public_key, .. }
+                    if *account_id == alice_account() =>
+                {
+                    Some(public_key)
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(keys, [&alice_pk(), &bob_pk(), &carol_pk()]);
+    }
+
+    #[test]
+    fn test_genesis_builder() {
+        let code = vec![1, 2, 3];
+        let mut genesis = GenesisBuilder::new(Genesis::default())
+            .account(alice_account(), 100)
+            .access_key(alice_account(), alice_pk(), AccessKey::full_access())
+            .account(bob_account(), 200)
+            .contract(alice_account(), code.clone())
+            .build();
+        let records = &genesis.force_read_records().0;
+
+        let account = |account_id: AccountId| {
+            records.iter().find_map(|record| match record {
+                StateRecord::Account { account_id: id, account } if *id == account_id => {
+                    Some(account.clone())
+                }
+                _ => None,
+            })
+        };
+        let alice = account(alice_account()).unwrap();
+        assert_eq!(alice.amount(), 100);
+        assert_eq!(alice.code_hash(), hash(&code));
+        assert_eq!(account(bob_account()).unwrap().amount(), 200);
+        assert!(records.iter().any(|record| matches!(
+            record,
+            StateRecord::AccessKey { account_id, public_key, .. }
+                if *account_id == alice_account() && *public_key == alice_pk()
+        )));
+        assert!(records.iter().any(|record| matches!(
+            record,
+            StateRecord::Contract { account_id, code: contract } if *account_id == alice_account() && *contract == code
+        )));
+    }
+
+    #[test]
+    fn test_upsert_account() {
+        let code = vec![1, 2, 3];
+        let mut genesis = GenesisBuilder::new(Genesis::default())
+            .account(alice_account(), 100)
+            .contract(alice_account(), code.clone())
+            .build();
+        upsert_account(&mut genesis, alice_account(), 500);
+        upsert_account(&mut genesis, bob_account(), 200);
+        let records = &genesis.force_read_records().0;
+
+        let accounts: Vec<(&AccountId, &Account)> = records
+            .iter()
+            .filter_map(|record| match record {
+                StateRecord::Account { account_id, account } => Some((account_id, account)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(accounts.len(), 2);
+        assert_eq!(accounts[0].0, &alice_account());
+        assert_eq!(accounts[0].1.amount(), 500);
+        assert_eq!(accounts[0].1.code_hash(), hash(&code));
+        assert_eq!(accounts[1].0, &bob_account());
+        assert_eq!(accounts[1].1.amount(), 200);
+    }
+
+    #[test]
+    fn test_remove_account() {
+        let mut genesis = GenesisBuilder::new(Genesis::default())
+            .account(alice_account(), 100)
+            .access_key(alice_account(), alice_pk(), AccessKey::full_access())
+            .contract(alice_account(), vec![1, 2, 3])
+            .account(bob_account(), 200)
+            .access_key(bob_account(), bob_pk(), AccessKey::full_access())
+            .build();
+        remove_account(&mut genesis, &alice_account());
+        let records = &genesis.force_read_records().0;
+
+        assert_eq!(records.len(), 2);
+        assert!(records.iter().all(|record| state_record_to_account_id(record) == &bob_account()));
+    }
+
+    #[test]
+    fn test_add_contract_twice() {
+        let (old_code, new_code) = (vec![1, 2, 3], vec![4, 5, 6]);
+        let mut genesis = GenesisBuilder::new(Genesis::default())
+            .account(alice_account(), 100)
+            .contract(alice_account(), old_code)
+            .contract(alice_account(), new_code.clone())
+            .build();
+        let records = &genesis.force_read_records().0;
+
+        let contracts: Vec<&Vec<u8>> = records
+            .iter()
+            .filter_map(|record| match record {
+                StateRecord::Contract { code, .. } => Some(code),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(contracts, [&new_code]);
+        assert!(records.iter().any(|record| matches!(
+            record,
+            StateRecord::Account { account, .. } if account.code_hash() == hash(&new_code)
+        )));
+    }
+
+    #[test]
+    fn test_random_account_with_key() {
+        let mut genesis = Genesis::default();
+        let (account_id, secret_key) = random_account_with_key(&mut genesis, 100);
+        let (other_account_id, _) = random_account_with_key(&mut genesis, 100);
+        assert_ne!(account_id, other_account_id);
+        let records = &genesis.force_read_records().0;
+
+        let keys: Vec<&PublicKey> = records
+            .iter()
+            .filter_map(|record| match record {
+                StateRecord::AccessKey { account_id: id, public_key, access_key }
+                    if *id == account_id =>
+                {
+                    assert_eq!(access_key, &AccessKey::full_access());
+                    Some(public_key)
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(keys, [&secret_key.public_key()]);
+    }
+
+    #[test]
+    fn test_find_records() {
+        let mut genesis = GenesisBuilder::new(Genesis::default())
+            .account(alice_account(), 100)
+            .access_key(alice_account(), alice_pk(), AccessKey::full_access())
+            .build();
+
+        assert_eq!(find_account(&mut genesis, &alice_account()).map(Account::amount), Some(100));
+        assert!(find_account(&mut genesis, &bob_account()).is_none());
+        assert_eq!(
+            find_access_key(&mut genesis, &alice_account(), &alice_pk()),
+            Some(&AccessKey::full_access())
+        );
+        assert!(find_access_key(&mut genesis, &alice_account(), &bob_pk()).is_none());
+        assert!(find_access_key(&mut genesis, &bob_account(), &alice_pk()).is_none());
+        assert_eq!(account_balance(&mut genesis, &alice_account()), Some(100));
+        assert_eq!(account_balance(&mut genesis, &bob_account()), None);
+    }
 }
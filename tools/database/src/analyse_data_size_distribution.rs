@@ -8,7 +8,7 @@ use strum::IntoEnumIterator;
 use unc_store::db::{Database, RocksDB};
 use unc_store::DBCol;
 
-use crate::utils::{open_rocksdb, resolve_column};
+use crate::utils::{open_rocksdb_with_temperature, print_columns, resolve_column};
 
 #[derive(Parser)]
 pub(crate) struct AnalyseDataSizeDistributionCommand {
@@ -16,6 +16,15 @@ pub(crate) struct AnalyseDataSizeDistributionCommand {
     #[arg(short, long)]
     column: Option<String>,
 
+    /// Print names of all columns and exit
+    #[arg(long)]
+    list_columns: bool,
+
+    /// Which database to analyse, hot or cold.  Cold is only available when
+    /// cold_store is configured.
+    #[arg(long, default_value = "hot")]
+    store_temperature: unc_store::Temperature,
+
     /// Number of count sizes to output
     #[arg(short, long, default_value_t = 100)]
     top_k: usize,
@@ -191,7 +200,12 @@ fn get_column_families(input_col: &Option<String>) -> anyhow::Result<Vec<DBCol>>
 
 impl AnalyseDataSizeDistributionCommand {
     pub(crate) fn run(&self, home: &PathBuf) -> anyhow::Result<()> {
-        let db = open_rocksdb(home, unc_store::Mode::ReadOnly)?;
+        if self.list_columns {
+            print_columns();
+            return Ok(());
+        }
+        let db =
+            open_rocksdb_with_temperature(home, unc_store::Mode::ReadOnly, self.store_temperature)?;
         let column_families = get_column_families(&self.column)?;
         let results = read_all_pairs(&db, &column_families);
         results.print_results(self.top_k);
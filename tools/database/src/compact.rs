@@ -1,4 +1,4 @@
-use crate::utils::{open_rocksdb, resolve_column};
+use crate::utils::{open_rocksdb, print_columns, resolve_column};
 use clap::Parser;
 use std::path::PathBuf;
 use unc_store::db::Database;
@@ -8,10 +8,18 @@ pub(crate) struct RunCompactionCommand {
     /// If specified only this column will compacted
     #[arg(short, long)]
     column: Option<String>,
+
+    /// Print names of all columns and exit
+    #[arg(long)]
+    list_columns: bool,
 }
 
 impl RunCompactionCommand {
     pub(crate) fn run(&self, home: &PathBuf) -> anyhow::Result<()> {
+        if self.list_columns {
+            print_columns();
+            return Ok(());
+        }
         let db = open_rocksdb(home, unc_store::Mode::ReadWrite)?;
         if let Some(col_name) = &self.column {
             db.compact_column(resolve_column(col_name)?)?;
@@ -1,4 +1,4 @@
-use crate::utils::open_state_snapshot;
+use crate::utils::{open_state_snapshot, SnapshotSelection};
 use anyhow::anyhow;
 use clap::Parser;
 use std::path::PathBuf;
@@ -9,11 +9,15 @@ use unc_store::{flat::FlatStorageManager, ShardUId, StoreUpdate};
 pub(crate) struct CorruptStateSnapshotCommand {
     #[clap(short, long)]
     shard_layout_version: ShardVersion,
+    /// Which state snapshot to corrupt: `latest` or the hash of the block the
+    /// snapshot was taken at.
+    #[clap(long, default_value = "latest")]
+    snapshot: SnapshotSelection,
 }
 
 impl CorruptStateSnapshotCommand {
     pub(crate) fn run(&self, home: &PathBuf) -> anyhow::Result<()> {
-        let store = open_state_snapshot(home, unc_store::Mode::ReadWrite)?;
+        let store = open_state_snapshot(home, unc_store::Mode::ReadWrite, &self.snapshot)?;
         let flat_storage_manager = FlatStorageManager::new(store.clone());
 
         let mut store_update = store.store_update();
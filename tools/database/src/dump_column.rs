@@ -0,0 +1,39 @@
+use crate::utils::{dump_column, open_rocksdb_readonly, print_columns, resolve_column};
+use clap::Parser;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+#[derive(Parser)]
+pub(crate) struct DumpColumnCommand {
+    /// Column to dump, see `--list-columns` for the names
+    #[arg(required_unless_present = "list_columns")]
+    column: Option<String>,
+
+    /// Print names of all columns and exit
+    #[arg(long)]
+    list_columns: bool,
+
+    /// Maximum number of entries to print
+    #[arg(short, long, default_value_t = 100)]
+    limit: usize,
+}
+
+impl DumpColumnCommand {
+    pub(crate) fn run(&self, home: &PathBuf) -> anyhow::Result<()> {
+        // Clap requires the column unless `--list-columns` is given.
+        let column = match &self.column {
+            Some(column) if !self.list_columns => column,
+            _ => {
+                print_columns();
+                return Ok(());
+            }
+        };
+        let col = resolve_column(column)?;
+        let rocksdb = Arc::new(open_rocksdb_readonly(home)?);
+        let store = unc_store::NodeStorage::new(rocksdb).get_hot_store();
+        for (key, value) in dump_column(&store, col, Some(self.limit))? {
+            println!("{} {}", hex::encode(key), hex::encode(value));
+        }
+        Ok(())
+    }
+}
@@ -5,8 +5,11 @@ mod block_iterators;
 pub mod commands;
 mod compact;
 mod corrupt;
+mod dump_column;
 mod make_snapshot;
 mod memtrie;
 mod run_migrations;
 mod state_perf;
 mod utils;
+
+pub use utils::flat_heads_all_shards;
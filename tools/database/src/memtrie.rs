@@ -1,4 +1,4 @@
-use crate::utils::{flat_head, flat_head_state_root, open_rocksdb};
+use crate::utils::{flat_head, flat_head_state_root, open_rocksdb_readonly};
 use framework::UncConfig;
 use std::path::Path;
 use std::sync::Arc;
@@ -19,7 +19,7 @@ pub struct LoadMemTrieCommand {
 
 impl LoadMemTrieCommand {
     pub fn run(&self, unc_config: UncConfig, home: &Path) -> anyhow::Result<()> {
-        let rocksdb = Arc::new(open_rocksdb(home, unc_store::Mode::ReadOnly)?);
+        let rocksdb = Arc::new(open_rocksdb_readonly(home)?);
         let store = unc_store::NodeStorage::new(rocksdb).get_hot_store();
         let genesis_config = &unc_config.genesis.config;
         // Note: this is not necessarily correct; it's just an estimate of the shard layout,
@@ -34,8 +34,8 @@ impl LoadMemTrieCommand {
         let shard_layout = epoch_manager.get_shard_layout(block_header.epoch_id()).unwrap();
 
         let shard_uid = ShardUId::from_shard_id_and_layout(self.shard_id, &shard_layout);
-        let state_root = flat_head_state_root(&store, &shard_uid);
-        let flat_head_height = flat_head(&store, &shard_uid).height;
+        let state_root = flat_head_state_root(&store, &shard_uid)?;
+        let flat_head_height = flat_head(&store, &shard_uid)?.height;
 
         let _trie = load_trie_from_flat_state(&store, shard_uid, state_root, flat_head_height)?;
         println!(
@@ -15,7 +15,7 @@ use unc_primitives::state::ValueRef;
 use unc_store::flat::store_helper::iter_flat_state_entries;
 use unc_store::{Store, TrieStorage};
 
-use crate::utils::open_rocksdb;
+use crate::utils::open_rocksdb_readonly;
 
 #[derive(Parser)]
 pub(crate) struct StatePerfCommand {
@@ -32,7 +32,7 @@ pub(crate) struct StatePerfCommand {
 
 impl StatePerfCommand {
     pub(crate) fn run(&self, home: &Path) -> anyhow::Result<()> {
-        let rocksdb = Arc::new(open_rocksdb(home, unc_store::Mode::ReadOnly)?);
+        let rocksdb = Arc::new(open_rocksdb_readonly(home)?);
         let store = unc_store::NodeStorage::new(rocksdb).get_hot_store();
         eprintln!("Start State perf test");
         let mut perf_context = PerfContext::new();
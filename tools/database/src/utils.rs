@@ -1,42 +1,103 @@
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use anyhow::anyhow;
 use strum::IntoEnumIterator;
 use unc_primitives::hash::CryptoHash;
-use unc_primitives::shard_layout::get_block_shard_uid;
+use unc_primitives::shard_layout::{get_block_shard_uid, ShardLayout};
 use unc_store::flat::{store_helper, BlockInfo};
 use unc_store::{DBCol, NodeStorage, ShardUId, Store};
 
 pub(crate) fn open_rocksdb(
     home: &Path,
     mode: unc_store::Mode,
+) -> anyhow::Result<unc_store::db::RocksDB> {
+    open_rocksdb_with_temperature(home, mode, unc_store::Temperature::Hot)
+}
+
+/// Opens the node's RocksDB in read-only mode.
+///
+/// This is the recommended entry point for tools which only inspect the
+/// database: any write through the returned handle fails, so pointing the tool
+/// at a copy of a live database can't corrupt it by accident.
+pub(crate) fn open_rocksdb_readonly(home: &Path) -> anyhow::Result<unc_store::db::RocksDB> {
+    open_rocksdb(home, unc_store::Mode::ReadOnly)
+}
+
+/// Same as [`open_rocksdb`] but can open the cold database of an archival node
+/// running with split storage, provided `cold_store` is configured.
+///
+/// The cold database holds the columns for which [`DBCol::is_cold`] is true,
+/// e.g. blocks, chunks, receipts and state, plus its own `DbVersion` and
+/// `BlockMisc`.  Other columns live in the hot database only.
+pub(crate) fn open_rocksdb_with_temperature(
+    home: &Path,
+    mode: unc_store::Mode,
+    temperature: unc_store::Temperature,
 ) -> anyhow::Result<unc_store::db::RocksDB> {
     let config = framework::config::Config::from_file_skip_validation(
         &home.join(framework::config::CONFIG_FILENAME),
     )?;
-    let store_config = &config.store;
-    let db_path = store_config.path.as_ref().cloned().unwrap_or_else(|| home.join("data"));
-    let rocksdb =
-        unc_store::db::RocksDB::open(&db_path, store_config, mode, unc_store::Temperature::Hot)?;
+    let (store_config, default_path) = match temperature {
+        unc_store::Temperature::Hot => (&config.store, "data"),
+        unc_store::Temperature::Cold => (
+            config.cold_store.as_ref().ok_or_else(|| anyhow!("cold_store is not configured"))?,
+            "cold-data",
+        ),
+    };
+    let db_path = store_config.path.as_ref().cloned().unwrap_or_else(|| home.join(default_path));
+    open_rocksdb_at(&db_path, store_config, mode, temperature)
+}
+
+/// Opens RocksDB in the given directory directly, without looking for it in a
+/// node home directory the way [`open_rocksdb`] does.
+pub(crate) fn open_rocksdb_at(
+    path: &Path,
+    store_config: &unc_store::StoreConfig,
+    mode: unc_store::Mode,
+    temperature: unc_store::Temperature,
+) -> anyhow::Result<unc_store::db::RocksDB> {
+    let rocksdb = unc_store::db::RocksDB::open(path, store_config, mode, temperature)?;
     Ok(rocksdb)
 }
 
-pub(crate) fn open_state_snapshot(home: &Path, mode: unc_store::Mode) -> anyhow::Result<Store> {
+/// Selects which of the state snapshots kept by the node to open.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) enum SnapshotSelection {
+    /// The most recently modified snapshot.
+    #[default]
+    Latest,
+    /// Snapshot taken at the block with the given hash, i.e. the one whose
+    /// directory is named by the hash.
+    Hash(CryptoHash),
+}
+
+impl std::str::FromStr for SnapshotSelection {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value == "latest" {
+            return Ok(Self::Latest);
+        }
+        value
+            .parse()
+            .map(Self::Hash)
+            .map_err(|err| format!("expected `latest` or a block hash, got {value}: {err}"))
+    }
+}
+
+pub(crate) fn open_state_snapshot(
+    home: &Path,
+    mode: unc_store::Mode,
+    selection: &SnapshotSelection,
+) -> anyhow::Result<Store> {
     let config = framework::config::Config::from_file_skip_validation(
         &home.join(framework::config::CONFIG_FILENAME),
     )?;
     let store_config = &config.store;
     let db_path = store_config.path.as_ref().cloned().unwrap_or_else(|| home.join("data"));
 
-    let state_snapshot_dir = db_path.join("state_snapshot");
-    let snapshots: Result<Vec<_>, _> = fs::read_dir(state_snapshot_dir)?.into_iter().collect();
-    let snapshots = snapshots?;
-    let &[snapshot_dir] = &snapshots.as_slice() else {
-        return Err(anyhow!("found more than one snapshot"));
-    };
-
-    let path = snapshot_dir.path();
+    let path = select_state_snapshot(&db_path.join("state_snapshot"), selection)?;
     println!("state snapshot path {path:?}");
 
     let opener = NodeStorage::opener(&path, false, &store_config, None);
@@ -46,27 +107,297 @@ pub(crate) fn open_state_snapshot(home: &Path, mode: unc_store::Mode) -> anyhow:
     Ok(store)
 }
 
+/// Returns path of the snapshot in `state_snapshot_dir` chosen by `selection`.
+/// If there's no such snapshot the error lists the available ones.
+fn select_state_snapshot(
+    state_snapshot_dir: &Path,
+    selection: &SnapshotSelection,
+) -> anyhow::Result<PathBuf> {
+    let snapshots = fs::read_dir(state_snapshot_dir)?.collect::<Result<Vec<_>, _>>()?;
+    let snapshot = match selection {
+        SnapshotSelection::Latest => {
+            let mut latest = None;
+            for snapshot in &snapshots {
+                let modified = snapshot.metadata()?.modified()?;
+                if latest.as_ref().map_or(true, |(latest_modified, _)| modified > *latest_modified)
+                {
+                    latest = Some((modified, snapshot));
+                }
+            }
+            latest.map(|(_, snapshot)| snapshot)
+        }
+        SnapshotSelection::Hash(hash) => {
+            let name = hash.to_string();
+            snapshots.iter().find(|snapshot| snapshot.file_name().to_str() == Some(name.as_str()))
+        }
+    };
+    match snapshot {
+        Some(snapshot) => Ok(snapshot.path()),
+        None => {
+            let mut available: Vec<_> = snapshots
+                .iter()
+                .map(|snapshot| snapshot.file_name().to_string_lossy().into_owned())
+                .collect();
+            available.sort();
+            Err(anyhow!(
+                "state snapshot {selection:?} not found in {state_snapshot_dir:?}, available snapshots: [{}]",
+                available.join(", ")
+            ))
+        }
+    }
+}
+
+/// Returns names of all columns, as accepted by [`resolve_column`].
+pub fn list_columns() -> Vec<&'static str> {
+    DBCol::iter().map(<&str>::from).collect()
+}
+
+/// Prints names of all columns, one per line.
+pub(crate) fn print_columns() {
+    for column in list_columns() {
+        println!("{column}");
+    }
+}
+
+/// Resolves column by its name ignoring case.  If there's no such column the
+/// error suggests the closest column name.
 pub(crate) fn resolve_column(col_name: &str) -> anyhow::Result<DBCol> {
-    DBCol::iter()
-        .filter(|db_col| <&str>::from(db_col) == col_name)
-        .next()
-        .ok_or_else(|| anyhow!("column {col_name} does not exist"))
+    if let Some(db_col) =
+        DBCol::iter().find(|db_col| <&str>::from(db_col).eq_ignore_ascii_case(col_name))
+    {
+        return Ok(db_col);
+    }
+    let lowercase_name = col_name.to_ascii_lowercase();
+    let closest = list_columns()
+        .into_iter()
+        .min_by_key(|name| levenshtein_distance(&name.to_ascii_lowercase(), &lowercase_name));
+    match closest {
+        Some(closest) => {
+            Err(anyhow!("column {col_name} does not exist, did you mean `{closest}`?"))
+        }
+        None => Err(anyhow!("column {col_name} does not exist")),
+    }
+}
+
+/// Number of single character insertions, deletions and substitutions needed
+/// to turn `a` into `b`.
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev_row: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut row = Vec::with_capacity(prev_row.len());
+        row.push(i + 1);
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = prev_row[j] + usize::from(a_char != *b_char);
+            let deletion = prev_row[j + 1] + 1;
+            let insertion = row[j] + 1;
+            row.push(substitution.min(deletion).min(insertion));
+        }
+        prev_row = row;
+    }
+    prev_row[b.len()]
 }
 
-pub fn flat_head_state_root(store: &Store, shard_uid: &ShardUId) -> CryptoHash {
+/// Returns up to `limit` key-value pairs of the column in key order.  `limit`
+/// of `None` reads the whole column which may not fit in memory for the large
+/// ones like `State`.
+pub fn dump_column(
+    store: &Store,
+    col: DBCol,
+    limit: Option<usize>,
+) -> anyhow::Result<Vec<(Box<[u8]>, Box<[u8]>)>> {
+    let iter = store.iter(col);
+    let entries = match limit {
+        Some(limit) => iter.take(limit).collect::<std::io::Result<_>>()?,
+        None => iter.collect::<std::io::Result<_>>()?,
+    };
+    Ok(entries)
+}
+
+/// Returns the state root of the shard at its flat storage head.
+pub fn flat_head_state_root(store: &Store, shard_uid: &ShardUId) -> anyhow::Result<CryptoHash> {
+    let flat_head = flat_head(store, shard_uid)?;
     let chunk: unc_primitives::types::chunk_extra::ChunkExtra = store
-        .get_ser(
-            DBCol::ChunkExtra,
-            &get_block_shard_uid(&flat_head(store, shard_uid).hash, shard_uid),
-        )
-        .unwrap()
-        .unwrap();
-    *chunk.state_root()
-}
-
-pub fn flat_head(store: &Store, shard_uid: &ShardUId) -> BlockInfo {
-    match store_helper::get_flat_storage_status(store, *shard_uid).unwrap() {
-        unc_store::flat::FlatStorageStatus::Ready(status) => status.flat_head,
-        other => panic!("invalid flat storage status {other:?}"),
+        .get_ser(DBCol::ChunkExtra, &get_block_shard_uid(&flat_head.hash, shard_uid))?
+        .ok_or_else(|| {
+            anyhow!("chunk extra for shard {shard_uid} at flat head {} not found", flat_head.hash)
+        })?;
+    Ok(*chunk.state_root())
+}
+
+/// Returns the flat storage head of the shard.  Fails if flat storage of the
+/// shard isn't ready, e.g. it's still being created.
+pub fn flat_head(store: &Store, shard_uid: &ShardUId) -> anyhow::Result<BlockInfo> {
+    match store_helper::get_flat_storage_status(store, *shard_uid)? {
+        unc_store::flat::FlatStorageStatus::Ready(status) => Ok(status.flat_head),
+        other => Err(anyhow!("flat storage for shard {shard_uid} is not ready: {other:?}")),
+    }
+}
+
+/// Returns flat storage heads of all shards of `shard_layout`.  Shards whose
+/// flat storage isn't ready are skipped with a warning.
+pub fn flat_heads_all_shards(
+    store: &Store,
+    shard_layout: &ShardLayout,
+) -> anyhow::Result<Vec<(ShardUId, BlockInfo)>> {
+    let mut flat_heads = Vec::new();
+    for shard_uid in shard_layout.shard_uids() {
+        match store_helper::get_flat_storage_status(store, shard_uid)? {
+            unc_store::flat::FlatStorageStatus::Ready(status) => {
+                flat_heads.push((shard_uid, status.flat_head))
+            }
+            other => {
+                tracing::warn!(%shard_uid, status = ?other, "flat storage is not ready, skipping shard")
+            }
+        }
+    }
+    Ok(flat_heads)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, SystemTime};
+    use unc_store::Temperature;
+
+    #[test]
+    fn test_select_state_snapshot() {
+        let dir = tempfile::tempdir().unwrap();
+        let old_hash = CryptoHash::hash_bytes(b"old");
+        let new_hash = CryptoHash::hash_bytes(b"new");
+        let now = SystemTime::now();
+        for (hash, modified) in [(new_hash, now), (old_hash, now - Duration::from_secs(60))] {
+            let path = dir.path().join(hash.to_string());
+            fs::create_dir(&path).unwrap();
+            fs::File::open(&path).unwrap().set_modified(modified).unwrap();
+        }
+
+        let latest = select_state_snapshot(dir.path(), &SnapshotSelection::Latest).unwrap();
+        assert_eq!(latest, dir.path().join(new_hash.to_string()));
+        let old = select_state_snapshot(dir.path(), &SnapshotSelection::Hash(old_hash)).unwrap();
+        assert_eq!(old, dir.path().join(old_hash.to_string()));
+
+        let missing = CryptoHash::hash_bytes(b"missing");
+        let err = select_state_snapshot(dir.path(), &SnapshotSelection::Hash(missing))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains(&old_hash.to_string()), "{err}");
+        assert!(err.contains(&new_hash.to_string()), "{err}");
+
+        assert_eq!("latest".parse(), Ok(SnapshotSelection::Latest));
+        assert_eq!(old_hash.to_string().parse(), Ok(SnapshotSelection::Hash(old_hash)));
+        assert!("foo".parse::<SnapshotSelection>().is_err());
+    }
+
+    #[test]
+    fn test_open_rocksdb_at() {
+        use unc_store::db::Database;
+
+        let dir = tempfile::tempdir().unwrap();
+        let store_config = unc_store::StoreConfig::test_config();
+        {
+            let db = open_rocksdb_at(
+                dir.path(),
+                &store_config,
+                unc_store::Mode::ReadWrite,
+                Temperature::Hot,
+            )
+            .unwrap();
+            let mut transaction = unc_store::db::DBTransaction::new();
+            transaction.set(DBCol::BlockMisc, b"key".to_vec(), b"value".to_vec());
+            db.write(transaction).unwrap();
+        }
+        let db =
+            open_rocksdb_at(dir.path(), &store_config, unc_store::Mode::ReadOnly, Temperature::Hot)
+                .unwrap();
+        assert_eq!(
+            db.get_raw_bytes(DBCol::BlockMisc, b"key").unwrap().as_deref(),
+            Some(&b"value"[..])
+        );
+    }
+
+    #[test]
+    fn test_open_rocksdb_readonly_rejects_writes() {
+        use unc_store::db::Database;
+
+        let home = tempfile::tempdir().unwrap();
+        framework::config::Config::default()
+            .write_to_file(&home.path().join(framework::config::CONFIG_FILENAME))
+            .unwrap();
+        drop(open_rocksdb(home.path(), unc_store::Mode::ReadWrite).unwrap());
+
+        let db = open_rocksdb_readonly(home.path()).unwrap();
+        let mut transaction = unc_store::db::DBTransaction::new();
+        transaction.set(DBCol::BlockMisc, b"key".to_vec(), b"value".to_vec());
+        assert!(db.write(transaction).is_err());
+    }
+
+    #[test]
+    fn test_dump_column() {
+        let store = unc_store::test_utils::create_test_store();
+        let mut store_update = store.store_update();
+        for key in [b"a", b"b", b"c"] {
+            store_update.set(DBCol::BlockMisc, key, b"value");
+        }
+        store_update.commit().unwrap();
+
+        let entries = dump_column(&store, DBCol::BlockMisc, None).unwrap();
+        let keys: Vec<&[u8]> = entries.iter().map(|(key, _)| &key[..]).collect();
+        assert_eq!(keys, [b"a", b"b", b"c"]);
+        assert!(entries.iter().all(|(_, value)| &value[..] == b"value"));
+
+        assert_eq!(dump_column(&store, DBCol::BlockMisc, Some(2)).unwrap().len(), 2);
+        assert!(dump_column(&store, DBCol::Block, Some(2)).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_flat_heads_all_shards() {
+        use unc_store::flat::{FlatStorageReadyStatus, FlatStorageStatus};
+
+        let store = unc_store::test_utils::create_test_store();
+        let shard_layout = ShardLayout::v0(2, 0);
+        let shard_uids: Vec<ShardUId> = shard_layout.shard_uids().collect();
+        let head = BlockInfo::genesis(CryptoHash::hash_bytes(b"head"), 10);
+        let mut store_update = store.store_update();
+        store_helper::set_flat_storage_status(
+            &mut store_update,
+            shard_uids[0],
+            FlatStorageStatus::Ready(FlatStorageReadyStatus { flat_head: head }),
+        );
+        store_helper::set_flat_storage_status(
+            &mut store_update,
+            shard_uids[1],
+            FlatStorageStatus::Empty,
+        );
+        store_update.commit().unwrap();
+
+        assert_eq!(
+            flat_heads_all_shards(&store, &shard_layout).unwrap(),
+            vec![(shard_uids[0], head)]
+        );
+        assert!(flat_head(&store, &shard_uids[1]).is_err());
+    }
+
+    #[test]
+    fn test_resolve_column() {
+        assert_eq!(resolve_column("ChunkExtra").unwrap(), DBCol::ChunkExtra);
+        assert_eq!(resolve_column("chunkextra").unwrap(), DBCol::ChunkExtra);
+        let err = resolve_column("ChunkExtr").unwrap_err().to_string();
+        assert!(err.contains("did you mean `ChunkExtra`?"), "{err}");
+    }
+
+    #[test]
+    fn test_list_columns() {
+        let columns = list_columns();
+        assert!(!columns.is_empty());
+        assert!(columns.contains(&"ChunkExtra"));
+    }
+
+    #[test]
+    fn test_levenshtein_distance() {
+        assert_eq!(levenshtein_distance("", ""), 0);
+        assert_eq!(levenshtein_distance("abc", ""), 3);
+        assert_eq!(levenshtein_distance("kitten", "sitting"), 3);
+        assert_eq!(levenshtein_distance("block", "block"), 0);
     }
 }
@@ -13,7 +13,7 @@ use std::fmt::Write;
 use std::pin::Pin;
 use std::time::{Duration, Instant};
 use unc_client::ViewClientActor;
-use unc_crypto::{PublicKey, SecretKey};
+use unc_crypto::{PublicKey, Signer};
 use unc_indexer::StreamerMessage;
 use unc_indexer_primitives::{IndexerExecutionOutcomeWithReceipt, IndexerTransactionWithOutcome};
 use unc_primitives::hash::CryptoHash;
@@ -306,7 +306,7 @@ impl TxTracker {
         db: &DB,
         signer_id: &AccountId,
         public_key: &PublicKey,
-        secret_key: &SecretKey,
+        signer: &dyn Signer,
     ) -> anyhow::Result<TargetNonce> {
         let access_key = (signer_id.clone(), public_key.clone());
         if !self.nonces.contains_key(&access_key) {
@@ -324,7 +324,7 @@ impl TxTracker {
             if first_nonce.is_none() {
                 first_nonce = Some(tx.target_nonce());
             }
-            tx.inc_target_nonce(secret_key)
+            tx.inc_target_nonce(signer)
         }
         match first_nonce {
             Some(n) => {
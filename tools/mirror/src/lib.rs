@@ -19,7 +19,7 @@ use unc_client_primitives::types::{
     GetBlock, GetBlockError, GetChunkError, GetExecutionOutcomeError, GetReceiptError, Query,
     QueryError, Status,
 };
-use unc_crypto::{PublicKey, SecretKey};
+use unc_crypto::{PublicKey, SecretKey, Signer};
 use unc_indexer::{Indexer, StreamerMessage};
 use unc_o11y::WithSpanContextExt;
 use unc_primitives::hash::CryptoHash;
@@ -624,7 +624,7 @@ impl MappedTx {
         source_receiver_id: AccountId,
         target_signer_id: AccountId,
         target_receiver_id: AccountId,
-        target_secret_key: &SecretKey,
+        signer: &dyn Signer,
         target_public_key: PublicKey,
         actions: Vec<Action>,
         nonce: Nonce,
@@ -641,7 +641,7 @@ impl MappedTx {
         );
         target_tx.actions = actions;
         let target_tx = SignedTransaction::new(
-            target_secret_key.sign(&target_tx.get_hash_and_size().0.as_ref()),
+            signer.sign(&target_tx.get_hash_and_size().0.as_ref()),
             target_tx,
         );
         Self {
@@ -654,11 +654,11 @@ impl MappedTx {
         }
     }
 
-    fn inc_nonce(&mut self, target_secret_key: &SecretKey) {
+    fn inc_nonce(&mut self, signer: &dyn Signer) {
         let mut tx = self.target_tx.transaction.clone();
         tx.nonce += 1;
         self.target_tx =
-            SignedTransaction::new(target_secret_key.sign(&tx.get_hash_and_size().0.as_ref()), tx);
+            SignedTransaction::new(signer.sign(&tx.get_hash_and_size().0.as_ref()), tx);
     }
 }
 
@@ -708,7 +708,7 @@ impl TargetChainTx {
         source_receiver_id: AccountId,
         target_signer_id: AccountId,
         target_receiver_id: AccountId,
-        target_secret_key: &SecretKey,
+        signer: &dyn Signer,
         target_public_key: PublicKey,
         actions: Vec<Action>,
         nonce: Nonce,
@@ -721,7 +721,7 @@ impl TargetChainTx {
             source_receiver_id,
             target_signer_id,
             target_receiver_id,
-            target_secret_key,
+            signer,
             target_public_key,
             actions,
             nonce,
@@ -769,9 +769,9 @@ impl TargetChainTx {
         }
     }
 
-    fn inc_target_nonce(&mut self, target_secret_key: &SecretKey) {
+    fn inc_target_nonce(&mut self, signer: &dyn Signer) {
         match self {
-            Self::Ready(t) => t.inc_nonce(target_secret_key),
+            Self::Ready(t) => t.inc_nonce(signer),
             Self::AwaitingNonce(t) => {
                 if let Some(n) = &mut t.target_nonce.nonce {
                     *n += 1;
@@ -1090,7 +1090,7 @@ impl<T: ChainAccess> TxMirror<T> {
                 source_receiver_id,
                 target_signer_id,
                 target_receiver_id,
-                &target_secret_key,
+                target_secret_key,
                 target_public_key,
                 actions,
                 target_nonce.nonce.unwrap(),